@@ -1,37 +1,211 @@
-use crate::coord::Coord;
+use crate::point::{Point, PointT};
 
-#[derive(Clone, Copy, Debug, dbg_pls::DebugPls)]
-pub struct Bounds {
-    pub min: Coord,
-    pub max: Coord,
+/// An axis-aligned box between `min` and `max`, both inclusive. The box is
+/// empty if `min` is greater than `max` along any axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, dbg_pls::DebugPls)]
+pub struct BoundingBox<const N: usize> {
+    pub min: Point<N>,
+    pub max: Point<N>,
 }
 
-impl Bounds {
-    pub fn new() -> Bounds {
-        Bounds {
-            min: Coord::MAX,
-            max: Coord::MIN,
+pub type Bounds = BoundingBox<2>;
+pub type Bounds3 = BoundingBox<3>;
+
+impl<const N: usize> BoundingBox<N> {
+    pub fn new() -> BoundingBox<N> {
+        BoundingBox {
+            min: Point::MAX,
+            max: Point::MIN,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        (0..N).any(|axis| self.min[axis] > self.max[axis])
+    }
+
+    pub fn with(self, point: &Point<N>) -> BoundingBox<N> {
+        if self.is_empty() {
+            return BoundingBox {
+                min: *point,
+                max: *point,
+            };
+        }
+
+        BoundingBox {
+            min: self.min.min(*point),
+            max: self.max.max(*point),
+        }
+    }
+
+    pub fn contains(&self, point: &Point<N>) -> bool {
+        (0..N).all(|axis| (self.min[axis]..=self.max[axis]).contains(&point[axis]))
+    }
+
+    /// Grows the box by `n` along every side. Empty boxes stay empty.
+    pub fn expand(self, n: PointT) -> BoundingBox<N> {
+        if self.is_empty() {
+            return self;
+        }
+
+        BoundingBox {
+            min: self.min - Point::splat(n),
+            max: self.max + Point::splat(n),
+        }
+    }
+
+    /// The number of points inside the box.
+    pub fn volume(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        (0..N)
+            .map(|axis| (self.max[axis] - self.min[axis] + 1) as usize)
+            .product()
+    }
+
+    /// The smallest box containing both boxes.
+    pub fn union(self, other: BoundingBox<N>) -> BoundingBox<N> {
+        if self.is_empty() {
+            return other;
+        }
+        if other.is_empty() {
+            return self;
+        }
+
+        BoundingBox {
+            min: self.min.min(other.min),
+            max: self.max.max(other.max),
         }
     }
 
-    fn with(self, coord: &Coord) -> Bounds {
-        Bounds {
-            min: self.min.min(*coord),
-            max: self.max.max(*coord),
+    pub fn intersection(self, other: BoundingBox<N>) -> BoundingBox<N> {
+        BoundingBox {
+            min: self.min.max(other.min),
+            max: self.max.min(other.max),
         }
     }
+
+    /// Every point inside the box, with the first axis changing fastest.
+    pub fn points(&self) -> impl Iterator<Item = Point<N>> {
+        let bounds = *self;
+        let mut next = Some(self.min).filter(|_| !self.is_empty());
+
+        std::iter::from_fn(move || {
+            let curr = next?;
+
+            let mut succ = curr;
+            next = (0..N).find_map(|axis| {
+                if succ[axis] < bounds.max[axis] {
+                    succ[axis] += 1;
+                    Some(succ)
+                } else {
+                    succ[axis] = bounds.min[axis];
+                    None
+                }
+            });
+
+            Some(curr)
+        })
+    }
+}
+
+impl<const N: usize> Default for BoundingBox<N> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 trait Internal {}
 
 #[allow(private_bounds)]
-pub trait CoordBounded: Internal {
-    fn coord_bounds(self) -> Bounds;
+pub trait CoordBounded<const N: usize>: Internal {
+    fn coord_bounds(self) -> BoundingBox<N>;
+}
+
+impl<'a, const N: usize, I: Iterator<Item = &'a Point<N>>> Internal for I {}
+impl<'a, const N: usize, I: Iterator<Item = &'a Point<N>>> CoordBounded<N> for I {
+    fn coord_bounds(self) -> BoundingBox<N> {
+        self.fold(BoundingBox::new(), |bounds, curr| bounds.with(curr))
+    }
 }
 
-impl<'a, I: Iterator<Item = &'a Coord>> Internal for I {}
-impl<'a, I: Iterator<Item = &'a Coord>> CoordBounded for I {
-    fn coord_bounds(self) -> Bounds {
-        self.fold(Bounds::new(), |bounds, curr| bounds.with(curr))
+#[cfg(test)]
+mod tests {
+    use super::{Bounds, Bounds3, CoordBounded};
+    use crate::{coord::Coord, coord3::Coord3};
+
+    #[test]
+    fn bounds3() {
+        let points = [Coord3::new(1, 2, 3), Coord3::new(-1, 4, 0)];
+        let bounds = points.iter().coord_bounds();
+
+        assert_eq!(bounds.min, Coord3::new(-1, 2, 0));
+        assert_eq!(bounds.max, Coord3::new(1, 4, 3));
+        assert_eq!(bounds.volume(), 3 * 3 * 4);
+        assert_eq!(bounds.points().count(), bounds.volume());
+        assert!(bounds.points().all(|p| bounds.contains(&p)));
+        assert!(!bounds.contains(&Coord3::new(0, 0, 0)));
+        assert_eq!(bounds.expand(1).volume(), 5 * 5 * 6);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = Bounds {
+            min: Coord::new(0, 0),
+            max: Coord::new(3, 3),
+        };
+        let b = Bounds {
+            min: Coord::new(2, -1),
+            max: Coord::new(5, 1),
+        };
+
+        assert_eq!(
+            a.intersection(b),
+            Bounds {
+                min: Coord::new(2, 0),
+                max: Coord::new(3, 1),
+            }
+        );
+        assert_eq!(a.union(b).volume(), 6 * 5);
+        assert!(a.intersection(b.expand(-2)).is_empty());
+        assert_eq!(Bounds3::new().points().count(), 0);
+        assert_eq!(Bounds3::new().expand(1), Bounds3::new());
+    }
+
+    #[test]
+    fn empty_boxes() {
+        let a = Bounds {
+            min: Coord::new(0, 0),
+            max: Coord::new(1, 1),
+        };
+        let b = Bounds {
+            min: Coord::new(5, 5),
+            max: Coord::new(6, 6),
+        };
+
+        let empty = a.intersection(b);
+        assert_eq!(empty.min, Coord::new(5, 5));
+        assert_eq!(empty.max, Coord::new(1, 1));
+        assert!(empty.is_empty());
+
+        assert_eq!(empty.union(a), a);
+        assert_eq!(b.union(empty), b);
+        assert!(empty.union(empty).is_empty());
+        assert_eq!(
+            empty.with(&Coord::new(9, 9)),
+            [Coord::new(9, 9)].iter().coord_bounds()
+        );
+    }
+
+    #[test]
+    fn points_order() {
+        let bounds = [Coord::new(0, 0), Coord::new(1, 1)].iter().coord_bounds();
+        let points: Vec<_> = bounds.points().collect();
+
+        assert_eq!(
+            points,
+            [(0, 0), (1, 0), (0, 1), (1, 1)].map(|(x, y)| Coord::new(x, y))
+        );
     }
 }
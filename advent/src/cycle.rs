@@ -0,0 +1,202 @@
+//! Detecting cycles in simulations and fast-forwarding through them.
+//!
+//! A simulation is described by its initial state, a `step` function, a
+//! `key` identifying equivalent states and a `measure` of the quantity of
+//! interest. Once a key repeats, the measure is assumed to grow by the same
+//! amount every cycle, which lets [`Cycle::measure_at`] answer for any
+//! number of steps.
+
+use std::{collections::HashMap, hash::Hash};
+
+use crate::{Error, Result};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// The number of steps before the cycle is entered.
+    pub start: usize,
+    pub len: usize,
+    /// The measure after every step up to and including `start + len`.
+    history: Vec<isize>,
+}
+
+impl Cycle {
+    /// The measured quantity after `steps` steps.
+    pub fn measure_at(&self, steps: usize) -> isize {
+        if let Some(&measure) = self.history.get(steps) {
+            return measure;
+        }
+
+        let per_cycle = self.history[self.start + self.len] - self.history[self.start];
+        let cycles = (steps - self.start) / self.len;
+        let offset = (steps - self.start) % self.len;
+
+        self.history[self.start + offset] + cycles as isize * per_cycle
+    }
+}
+
+/// Finds a cycle by remembering every key in a hash map. States without a
+/// key (`None`) are stepped over and can't start or end a cycle.
+///
+/// Fails with [`Error::NoSolution`] if no key repeats within `max_steps`
+/// steps.
+pub fn find_cycle<S, K, Step, Key, Measure>(
+    mut state: S,
+    mut step: Step,
+    mut key: Key,
+    mut measure: Measure,
+    max_steps: usize,
+) -> Result<Cycle>
+where
+    K: Hash + Eq,
+    Step: FnMut(&mut S),
+    Key: FnMut(&S) -> Option<K>,
+    Measure: FnMut(&S) -> isize,
+{
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+
+    for steps in 0..=max_steps {
+        history.push(measure(&state));
+
+        if let Some(key) = key(&state) {
+            if let Some(&start) = seen.get(&key) {
+                return Ok(Cycle {
+                    start,
+                    len: steps - start,
+                    history,
+                });
+            }
+
+            seen.insert(key, steps);
+        }
+
+        step(&mut state);
+    }
+
+    Err(Error::NoSolution)
+}
+
+/// Finds a cycle with Brent's algorithm, keeping only a couple of states and
+/// keys in memory. Every state must have a key.
+///
+/// Fails with [`Error::NoSolution`] if the search for the cycle takes more
+/// than `max_steps` steps.
+pub fn brent<S, K, Step, Key, Measure>(
+    initial: S,
+    mut step: Step,
+    mut key: Key,
+    mut measure: Measure,
+    max_steps: usize,
+) -> Result<Cycle>
+where
+    S: Clone,
+    K: Eq,
+    Step: FnMut(&mut S),
+    Key: FnMut(&S) -> K,
+    Measure: FnMut(&S) -> isize,
+{
+    // Find the cycle length by moving the tortoise to the hare at every
+    // power of two.
+    let mut power = 1;
+    let mut len = 1;
+    let mut tortoise = key(&initial);
+    let mut hare = initial.clone();
+    step(&mut hare);
+    let mut steps = 1;
+
+    while tortoise != key(&hare) {
+        if steps >= max_steps {
+            return Err(Error::NoSolution);
+        }
+
+        if power == len {
+            tortoise = key(&hare);
+            power *= 2;
+            len = 0;
+        }
+
+        step(&mut hare);
+        len += 1;
+        steps += 1;
+    }
+
+    // With the hare `len` steps ahead, they meet at the start of the cycle.
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    (0..len).for_each(|_| step(&mut hare));
+
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    let mut state = initial;
+    let mut history = vec![measure(&state)];
+    for _ in 0..start + len {
+        step(&mut state);
+        history.push(measure(&state));
+    }
+
+    Ok(Cycle {
+        start,
+        len,
+        history,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{brent, find_cycle};
+    use crate::Error;
+
+    // 3 leads into a cycle of length 100 starting at 7.
+    fn step(x: &mut u64) {
+        *x = (*x * 2 + 1) % 1000;
+    }
+
+    #[test]
+    fn both_agree() {
+        let hashed = find_cycle(3u64, step, |&x| Some(x), |&x| x as isize, 1000).unwrap();
+        let brent = brent(3u64, step, |&x| x, |&x| x as isize, 1000).unwrap();
+
+        assert_eq!((hashed.start, hashed.len), (1, 100));
+        assert_eq!(hashed, brent);
+    }
+
+    #[test]
+    fn fast_forward() {
+        // Counts the steps, so it should grow by one every step.
+        let cycle = find_cycle(
+            (3u64, 0isize),
+            |(x, count)| {
+                step(x);
+                *count += 1;
+            },
+            |&(x, _)| Some(x),
+            |&(_, count)| count,
+            1000,
+        )
+        .unwrap();
+
+        assert_eq!(cycle.measure_at(10), 10);
+        assert_eq!(cycle.measure_at(1_000_000_000_000), 1_000_000_000_000);
+    }
+
+    #[test]
+    fn no_cycle() {
+        let count = |x: &mut u64| *x += 1;
+
+        assert_eq!(
+            find_cycle(0u64, count, |&x| Some(x), |&x| x as isize, 1000),
+            Err(Error::NoSolution)
+        );
+        assert_eq!(
+            brent(0u64, count, |&x| x, |&x| x as isize, 1000),
+            Err(Error::NoSolution)
+        );
+        assert!(find_cycle(3u64, step, |&x| Some(x), |&x| x as isize, 100).is_err());
+        assert!(find_cycle(3u64, step, |&x| Some(x), |&x| x as isize, 101).is_ok());
+    }
+}
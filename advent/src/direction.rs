@@ -0,0 +1,260 @@
+//! Cardinal ([`Direction`]) and cardinal plus ordinal ([`Compass`])
+//! directions.
+//!
+//! Steps follow the screen convention of [`Coord`], where north is `-y`.
+//! Puzzles where `y` grows upwards can use `step_y_up` instead.
+
+use crate::coord::Coord;
+
+/// The four cardinal directions, numbered clockwise from north.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, dbg_pls::DebugPls)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    fn from_index(idx: u8) -> Direction {
+        Self::ALL[idx as usize % 4]
+    }
+
+    /// Rotates by 90 degrees clockwise, i.e. turns right.
+    pub fn clockwise(self) -> Direction {
+        Self::from_index(self as u8 + 1)
+    }
+
+    /// Rotates by 90 degrees anticlockwise, i.e. turns left.
+    pub fn anticlockwise(self) -> Direction {
+        Self::from_index(self as u8 + 3)
+    }
+
+    pub fn opposite(self) -> Direction {
+        Self::from_index(self as u8 + 2)
+    }
+
+    /// All four directions, clockwise starting at `self`.
+    pub fn clockwise_from(self) -> impl Iterator<Item = Direction> + Clone {
+        (0..4).map(move |offset| Self::from_index(self as u8 + offset))
+    }
+
+    /// All four directions, anticlockwise starting at `self`.
+    pub fn anticlockwise_from(self) -> impl Iterator<Item = Direction> + Clone {
+        (0..4).map(move |offset| Self::from_index(self as u8 + 4 - offset))
+    }
+
+    pub fn step(self) -> Coord {
+        match self {
+            Direction::North => Coord::up(),
+            Direction::East => Coord::right(),
+            Direction::South => Coord::down(),
+            Direction::West => Coord::left(),
+        }
+    }
+
+    pub fn step_y_up(self) -> Coord {
+        match self {
+            Direction::North => Coord::down(),
+            Direction::South => Coord::up(),
+            dir => dir.step(),
+        }
+    }
+
+    pub fn from_step(step: Coord) -> Option<Direction> {
+        Self::ALL.into_iter().find(|dir| dir.step() == step)
+    }
+
+    /// Parses arrows (`^>v<`), compass letters (`NESW`) and relative
+    /// letters (`UDLR`), upper or lower case.
+    pub fn from_char(ch: char) -> Option<Direction> {
+        match ch {
+            '^' | 'N' | 'n' | 'U' | 'u' => Some(Direction::North),
+            '>' | 'E' | 'e' | 'R' | 'r' => Some(Direction::East),
+            'v' | 'S' | 's' | 'D' | 'd' => Some(Direction::South),
+            '<' | 'W' | 'w' | 'L' | 'l' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<',
+        }
+    }
+}
+
+/// The eight cardinal and ordinal directions, numbered clockwise from
+/// north.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, dbg_pls::DebugPls)]
+pub enum Compass {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Compass {
+    /// All directions, clockwise from north.
+    pub const ALL: [Compass; 8] = [
+        Compass::North,
+        Compass::NorthEast,
+        Compass::East,
+        Compass::SouthEast,
+        Compass::South,
+        Compass::SouthWest,
+        Compass::West,
+        Compass::NorthWest,
+    ];
+
+    fn from_index(idx: u8) -> Compass {
+        Self::ALL[idx as usize % 8]
+    }
+
+    /// Rotates by 45 degrees clockwise.
+    pub fn clockwise(self) -> Compass {
+        Self::from_index(self as u8 + 1)
+    }
+
+    /// Rotates by 45 degrees anticlockwise.
+    pub fn anticlockwise(self) -> Compass {
+        Self::from_index(self as u8 + 7)
+    }
+
+    pub fn clockwise_90(self) -> Compass {
+        Self::from_index(self as u8 + 2)
+    }
+
+    pub fn anticlockwise_90(self) -> Compass {
+        Self::from_index(self as u8 + 6)
+    }
+
+    pub fn opposite(self) -> Compass {
+        Self::from_index(self as u8 + 4)
+    }
+
+    /// All eight directions, clockwise starting at `self`.
+    pub fn clockwise_from(self) -> impl Iterator<Item = Compass> + Clone {
+        (0..8).map(move |offset| Self::from_index(self as u8 + offset))
+    }
+
+    /// All eight directions, anticlockwise starting at `self`.
+    pub fn anticlockwise_from(self) -> impl Iterator<Item = Compass> + Clone {
+        (0..8).map(move |offset| Self::from_index(self as u8 + 8 - offset))
+    }
+
+    /// The direction itself if it's cardinal, `None` if it's ordinal.
+    pub fn cardinal(self) -> Option<Direction> {
+        match self as u8 % 2 {
+            0 => Some(Direction::from_index(self as u8 / 2)),
+            _ => None,
+        }
+    }
+
+    pub fn step(self) -> Coord {
+        match self.cardinal() {
+            Some(dir) => dir.step(),
+            None => self.anticlockwise().step() + self.clockwise().step(),
+        }
+    }
+
+    pub fn step_y_up(self) -> Coord {
+        match self.cardinal() {
+            Some(dir) => dir.step_y_up(),
+            None => self.anticlockwise().step_y_up() + self.clockwise().step_y_up(),
+        }
+    }
+
+    pub fn from_step(step: Coord) -> Option<Compass> {
+        Self::ALL.into_iter().find(|dir| dir.step() == step)
+    }
+}
+
+impl From<Direction> for Compass {
+    fn from(dir: Direction) -> Self {
+        Compass::from_index(dir as u8 * 2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Compass, Direction};
+    use crate::coord::Coord;
+
+    #[test]
+    fn rotations() {
+        assert_eq!(Direction::North.clockwise(), Direction::East);
+        assert_eq!(Direction::North.anticlockwise(), Direction::West);
+        assert_eq!(Direction::West.opposite(), Direction::East);
+
+        assert_eq!(Compass::NorthWest.clockwise(), Compass::North);
+        assert_eq!(Compass::North.anticlockwise_90(), Compass::West);
+        assert_eq!(Compass::SouthEast.opposite(), Compass::NorthWest);
+    }
+
+    #[test]
+    fn iteration_orders() {
+        assert_eq!(
+            Direction::South.clockwise_from().collect::<Vec<_>>(),
+            [
+                Direction::South,
+                Direction::West,
+                Direction::North,
+                Direction::East
+            ]
+        );
+        assert_eq!(
+            Direction::South.anticlockwise_from().collect::<Vec<_>>(),
+            [
+                Direction::South,
+                Direction::East,
+                Direction::North,
+                Direction::West
+            ]
+        );
+        assert_eq!(
+            Compass::East.clockwise_from().nth(3),
+            Some(Compass::SouthWest)
+        );
+    }
+
+    #[test]
+    fn steps() {
+        assert_eq!(Compass::NorthEast.step(), Coord::new(1, -1));
+        assert_eq!(Compass::NorthEast.step_y_up(), Coord::new(1, 1));
+        assert_eq!(Direction::South.step_y_up(), Coord::new(0, -1));
+        assert_eq!(Compass::from(Direction::West), Compass::West);
+
+        for dir in Compass::ALL {
+            assert_eq!(Compass::from_step(dir.step()), Some(dir));
+            assert_eq!(dir.cardinal().is_some(), dir.step().manhattan() == 1);
+        }
+    }
+
+    #[test]
+    fn parsing() {
+        for chars in ["^>v<", "NESW", "URDL"] {
+            let dirs: Vec<_> = chars.chars().filter_map(Direction::from_char).collect();
+            assert_eq!(dirs, Direction::ALL);
+        }
+
+        assert_eq!(Direction::from_char('x'), None);
+    }
+}
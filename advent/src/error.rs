@@ -0,0 +1,79 @@
+use std::fmt::Display;
+
+use crate::parsers::ParseError;
+
+/// Everything that can go wrong solving a puzzle.
+#[derive(Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input could not be read.
+    Io(String),
+    Parse(ParseError),
+    /// The input parsed, but describes a puzzle that doesn't make sense.
+    InvalidState(String),
+    NoSolution,
+    /// The command line asked for something that can't be done.
+    Usage(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Io(err) => write!(f, "Failed to read the input: {}", err),
+            Error::Parse(err) => write!(f, "Failed to parse the input: {}", err),
+            Error::InvalidState(msg) => write!(f, "Invalid puzzle: {}", msg),
+            Error::NoSolution => write!(f, "The puzzle doesn't have a solution"),
+            Error::Usage(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+/// Same as `Display`, so that returning an error from `main` prints a
+/// readable message.
+impl std::fmt::Debug for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::Parse(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err.to_string())
+    }
+}
+
+/// Builds an [`Error::InvalidState`] from a format string.
+#[macro_export]
+macro_rules! invalid {
+    ( $( $arg:tt )* ) => {
+        $crate::error::Error::InvalidState(format!($( $arg )*))
+    };
+}
+
+/// Builds an [`Error::Usage`] from a format string.
+#[macro_export]
+macro_rules! usage {
+    ( $( $arg:tt )* ) => {
+        $crate::error::Error::Usage(format!($( $arg )*))
+    };
+}
+
+/// Returns an [`Error::InvalidState`] from the enclosing function unless
+/// the condition holds.
+#[macro_export]
+macro_rules! ensure {
+    ( $cond:expr, $( $arg:tt )* ) => {
+        if !$cond {
+            return Err($crate::invalid!($( $arg )*));
+        }
+    };
+}
@@ -0,0 +1,241 @@
+use std::{
+    fmt::Display,
+    ops::{Index, IndexMut},
+};
+
+use crate::coord::{ContainsCoord, Coord};
+
+#[derive(Clone, Debug, PartialEq, Eq, dbg_pls::DebugPls)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid by calling `f` for every coord in row-major order.
+    pub fn from_fn<F>(width: usize, height: usize, mut f: F) -> Grid<T>
+    where
+        F: FnMut(Coord) -> T,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coord::new(x as isize, y as isize)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from its cells in row-major order.
+    pub fn from_cells(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "Wrong number of cells");
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character, one row per line. All lines must be
+    /// the same length.
+    pub fn from_chars<F>(input: &str, mut f: F) -> Grid<T>
+    where
+        F: FnMut(char) -> T,
+    {
+        let width = input.lines().next().map_or(0, |l| l.chars().count());
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let len_before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            assert_eq!(
+                cells.len() - len_before,
+                width,
+                "Line {} has a different width than the first line",
+                height
+            );
+
+            height += 1;
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Like `from_chars`, but lines may differ in length and are padded
+    /// with `pad` up to the longest one.
+    pub fn from_chars_padded<F>(input: &str, pad: T, mut f: F) -> Grid<T>
+    where
+        T: Clone,
+        F: FnMut(char) -> T,
+    {
+        let width = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let len_before = cells.len();
+            cells.extend(line.chars().map(&mut f));
+            cells.resize(len_before + width, pad.clone());
+            height += 1;
+        }
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    fn cell_idx(&self, coord: Coord) -> Option<usize> {
+        if self.contains_coord(coord) {
+            Some(coord.y as usize * self.width + coord.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.cell_idx(coord).map(|idx| &self.cells[idx])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.cell_idx(coord).map(|idx| &mut self.cells[idx])
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height)
+            .flat_map(move |y| (0..width).map(move |x| Coord::new(x as isize, y as isize)))
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = (Coord, &T)> + Clone {
+        assert!(y < self.height, "Row {} out of bounds", y);
+        (0..self.width).map(move |x| {
+            let coord = Coord::new(x as isize, y as isize);
+            (coord, &self[coord])
+        })
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = (Coord, &T)> + Clone {
+        assert!(x < self.width, "Column {} out of bounds", x);
+        (0..self.height).map(move |y| {
+            let coord = Coord::new(x as isize, y as isize);
+            (coord, &self[coord])
+        })
+    }
+
+    pub fn rows(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Coord, &T)> + Clone> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn columns(
+        &self,
+    ) -> impl Iterator<Item = impl DoubleEndedIterator<Item = (Coord, &T)> + Clone> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Orthogonal neighbours of `coord` that lie inside the grid.
+    pub fn neighbours(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Coord::left_down_right_up()
+            .into_iter()
+            .map(move |step| coord + step)
+            .filter(|&neighbour| self.contains_coord(neighbour))
+    }
+
+    pub fn position<P>(&self, mut predicate: P) -> Option<Coord>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        self.iter()
+            .find_map(|(coord, cell)| Some(coord).filter(|_| predicate(cell)))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> ContainsCoord for Grid<T> {
+    fn contains_coord(&self, coord: Coord) -> bool {
+        coord.x >= 0
+            && (coord.x as usize) < self.width
+            && coord.y >= 0
+            && (coord.y as usize) < self.height
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, index: Coord) -> &Self::Output {
+        self.get(index)
+            .unwrap_or_else(|| panic!("Coord {} out of the grid bounds", index))
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, index: Coord) -> &mut Self::Output {
+        self.get_mut(index)
+            .unwrap_or_else(|| panic!("Coord {} out of the grid bounds", index))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.rows() {
+            for (_, cell) in row {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
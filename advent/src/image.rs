@@ -0,0 +1,868 @@
+//! Pictures of grid states, saved as SVG or as PNG.
+//!
+//! A [`Picture`] colours each cell of a grid, and can have paths and
+//! markers drawn over it and a legend below. Days save theirs with
+//! [`save`], which does nothing unless `--images <dir>` was given.
+
+use std::{cell::RefCell, fmt::Write as _, fs, path::Path};
+
+use crate::{
+    bounds::Bounds, coord::Coord, grid::Grid, info, sparse_grid::SparseGrid, usage, Error, Result,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// The colour a fraction `t` of the way from `from` to `to`.
+    pub fn mix(from: Rgb, to: Rgb, t: f64) -> Rgb {
+        let t = t.clamp(0., 1.);
+        let channel = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Rgb(
+            channel(from.0, to.0),
+            channel(from.1, to.1),
+            channel(from.2, to.2),
+        )
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+/// A grid's cells as coloured squares, `scale` pixels wide.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Picture {
+    bounds: Bounds,
+    /// The colour of each cell, row by row. `None` shows the background.
+    cells: Vec<Option<Rgb>>,
+    background: Rgb,
+    scale: usize,
+    paths: Vec<(Vec<Coord>, Rgb)>,
+    markers: Vec<(Coord, Rgb)>,
+    legend: Vec<(String, Rgb)>,
+}
+
+/// The height of a legend line, and the size of its font's pixels.
+const LEGEND_LINE: usize = 14;
+const FONT_SCALE: usize = 2;
+
+impl Picture {
+    /// Colours every cell inside `bounds`.
+    pub fn new(bounds: Bounds, colour: impl Fn(Coord) -> Option<Rgb>) -> Picture {
+        Picture {
+            bounds,
+            cells: bounds.points().map(colour).collect(),
+            background: Rgb::WHITE,
+            scale: 4,
+            paths: Vec::new(),
+            markers: Vec::new(),
+            legend: Vec::new(),
+        }
+    }
+
+    pub fn from_grid<T>(grid: &Grid<T>, colour: impl Fn(Coord, &T) -> Option<Rgb>) -> Picture {
+        let bounds = Bounds {
+            min: Coord::zero(),
+            max: Coord::new(grid.width() as isize - 1, grid.height() as isize - 1),
+        };
+        Picture::new(bounds, |coord| colour(coord, &grid[coord]))
+    }
+
+    /// Colours the cells inside the grid's bounds, set or not.
+    pub fn from_sparse<T>(
+        grid: &SparseGrid<T>,
+        colour: impl Fn(Coord, Option<&T>) -> Option<Rgb>,
+    ) -> Picture {
+        Picture::new(grid.bounds(), |coord| colour(coord, grid.get(coord)))
+    }
+
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    pub fn background(mut self, colour: Rgb) -> Self {
+        self.background = colour;
+        self
+    }
+
+    /// Draws a line through the centres of the cells, in order.
+    pub fn path(mut self, cells: impl IntoIterator<Item = Coord>, colour: Rgb) -> Self {
+        self.paths.push((cells.into_iter().collect(), colour));
+        self
+    }
+
+    /// Draws a dot over a cell.
+    pub fn marker(mut self, cell: Coord, colour: Rgb) -> Self {
+        self.markers.push((cell, colour));
+        self
+    }
+
+    pub fn legend(mut self, label: &str, colour: Rgb) -> Self {
+        self.legend.push((label.to_string(), colour));
+        self
+    }
+
+    fn grid_size(&self) -> (usize, usize) {
+        if self.bounds.is_empty() {
+            return (0, 0);
+        }
+
+        let size = self.bounds.max - self.bounds.min;
+        (
+            (size.x + 1) as usize * self.scale,
+            (size.y + 1) as usize * self.scale,
+        )
+    }
+
+    /// The size in pixels, with the legend below the grid.
+    pub fn size(&self) -> (usize, usize) {
+        let (grid_width, grid_height) = self.grid_size();
+        let legend_width = self
+            .legend
+            .iter()
+            .map(|(label, _)| LEGEND_LINE + 4 + label.chars().count() * 4 * FONT_SCALE)
+            .max()
+            .unwrap_or(0);
+
+        (
+            grid_width.max(legend_width).max(1),
+            (grid_height + self.legend.len() * LEGEND_LINE).max(1),
+        )
+    }
+
+    /// The centre of a cell, in pixels.
+    fn centre(&self, cell: Coord) -> (f64, f64) {
+        let pos = cell - self.bounds.min;
+        let half = self.scale as f64 / 2.;
+        (
+            pos.x as f64 * self.scale as f64 + half,
+            pos.y as f64 * self.scale as f64 + half,
+        )
+    }
+
+    fn line_width(&self) -> f64 {
+        (self.scale as f64 / 3.).max(1.)
+    }
+
+    /// The cells' colours as runs along each row, to draw fewer shapes.
+    fn runs(&self) -> impl Iterator<Item = (usize, usize, usize, Rgb)> + '_ {
+        let width = self.grid_size().0 / self.scale;
+        self.cells
+            .chunks(width.max(1))
+            .enumerate()
+            .flat_map(move |(y, row)| {
+                let mut runs = Vec::new();
+                let mut x = 0;
+                while x < row.len() {
+                    let len = row[x..].iter().take_while(|&&c| c == row[x]).count();
+                    if let Some(colour) = row[x] {
+                        runs.push((x, y, len, colour));
+                    }
+                    x += len;
+                }
+                runs
+            })
+    }
+
+    pub fn to_svg(&self) -> String {
+        let (width, height) = self.size();
+        let scale = self.scale;
+        let mut svg = String::new();
+
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{0}" height="{1}" viewBox="0 0 {0} {1}">"#,
+            width, height
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="{}" height="{}" fill="{}"/>"#,
+            width,
+            height,
+            self.background.hex()
+        );
+
+        svg.push_str("<g shape-rendering=\"crispEdges\">\n");
+        for (x, y, len, colour) in self.runs() {
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                x * scale,
+                y * scale,
+                len * scale,
+                scale,
+                colour.hex()
+            );
+        }
+        svg.push_str("</g>\n");
+
+        for (cells, colour) in &self.paths {
+            let points: Vec<String> = cells
+                .iter()
+                .map(|&cell| {
+                    let (x, y) = self.centre(cell);
+                    format!("{},{}", x, y)
+                })
+                .collect();
+            let _ = writeln!(
+                svg,
+                r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+                points.join(" "),
+                colour.hex(),
+                self.line_width()
+            );
+        }
+
+        for &(cell, colour) in &self.markers {
+            let (x, y) = self.centre(cell);
+            let _ = writeln!(
+                svg,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+                x,
+                y,
+                (scale as f64 / 2.).max(1.),
+                colour.hex()
+            );
+        }
+
+        let legend_top = self.grid_size().1;
+        for (idx, (label, colour)) in self.legend.iter().enumerate() {
+            let y = legend_top + idx * LEGEND_LINE + 2;
+            let _ = writeln!(
+                svg,
+                r#"<rect x="2" y="{}" width="10" height="10" fill="{}" stroke="black"/>"#,
+                y,
+                colour.hex()
+            );
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="monospace" font-size="11">{}</text>"#,
+                LEGEND_LINE + 4,
+                y + 9,
+                escape_xml(label)
+            );
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The picture's pixels, row by row.
+    pub fn pixels(&self) -> Vec<Rgb> {
+        let (width, height) = self.size();
+        let mut canvas = Canvas {
+            width,
+            pixels: vec![self.background; width * height],
+        };
+        let scale = self.scale;
+
+        for (x, y, len, colour) in self.runs() {
+            canvas.fill(x * scale, y * scale, len * scale, scale, colour);
+        }
+
+        let radius = self.line_width() / 2.;
+        for (cells, colour) in &self.paths {
+            for pair in cells.windows(2) {
+                canvas.line(self.centre(pair[0]), self.centre(pair[1]), radius, *colour);
+            }
+        }
+
+        for &(cell, colour) in &self.markers {
+            let centre = self.centre(cell);
+            canvas.line(centre, centre, (scale as f64 / 2.).max(1.), colour);
+        }
+
+        let legend_top = self.grid_size().1;
+        for (idx, (label, colour)) in self.legend.iter().enumerate() {
+            let y = legend_top + idx * LEGEND_LINE + 2;
+            canvas.fill(2, y, 10, 10, Rgb::BLACK);
+            canvas.fill(3, y + 1, 8, 8, *colour);
+            canvas.text(LEGEND_LINE + 4, y, label, Rgb::BLACK);
+        }
+
+        canvas.pixels
+    }
+
+    pub fn to_png(&self) -> Vec<u8> {
+        let (width, height) = self.size();
+        let pixels = self.pixels();
+
+        let mut raw = Vec::with_capacity((width * 3 + 1) * height);
+        for row in pixels.chunks(width) {
+            // No filter.
+            raw.push(0);
+            raw.extend(row.iter().flat_map(|p| [p.0, p.1, p.2]));
+        }
+
+        png::encode(width as u32, height as u32, &raw)
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+struct Canvas {
+    width: usize,
+    pixels: Vec<Rgb>,
+}
+
+impl Canvas {
+    fn height(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    fn set(&mut self, x: usize, y: usize, colour: Rgb) {
+        if x < self.width && y < self.height() {
+            self.pixels[y * self.width + x] = colour;
+        }
+    }
+
+    fn fill(&mut self, x: usize, y: usize, width: usize, height: usize, colour: Rgb) {
+        for py in y..y + height {
+            for px in x..x + width {
+                self.set(px, py, colour);
+            }
+        }
+    }
+
+    /// Fills the pixels within `radius` of the segment from `from` to `to`.
+    fn line(&mut self, from: (f64, f64), to: (f64, f64), radius: f64, colour: Rgb) {
+        let (dx, dy) = (to.0 - from.0, to.1 - from.1);
+        let len_sq = dx * dx + dy * dy;
+
+        let min_x = (from.0.min(to.0) - radius).floor().max(0.) as usize;
+        let min_y = (from.1.min(to.1) - radius).floor().max(0.) as usize;
+        let max_x = (from.0.max(to.0) + radius).ceil() as usize;
+        let max_y = (from.1.max(to.1) + radius).ceil() as usize;
+
+        for y in min_y..max_y.min(self.height()) {
+            for x in min_x..max_x.min(self.width) {
+                let (px, py) = (x as f64 + 0.5, y as f64 + 0.5);
+                let t = if len_sq == 0. {
+                    0.
+                } else {
+                    (((px - from.0) * dx + (py - from.1) * dy) / len_sq).clamp(0., 1.)
+                };
+                let (cx, cy) = (from.0 + t * dx - px, from.1 + t * dy - py);
+
+                if cx * cx + cy * cy <= radius * radius {
+                    self.set(x, y, colour);
+                }
+            }
+        }
+    }
+
+    fn text(&mut self, x: usize, y: usize, text: &str, colour: Rgb) {
+        for (idx, c) in text.chars().enumerate() {
+            let left = x + idx * 4 * FONT_SCALE;
+            for (row, bits) in glyph(c).into_iter().enumerate() {
+                for col in 0..3 {
+                    if bits & (0b100 >> col) != 0 {
+                        let (px, py) = (left + col * FONT_SCALE, y + row * FONT_SCALE);
+                        self.fill(px, py, FONT_SCALE, FONT_SCALE, colour);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The rows of a 3×5 glyph, with the leftmost pixel as the highest bit.
+/// Lowercase letters are drawn as uppercase.
+fn glyph(c: char) -> [u8; 5] {
+    match c.to_ascii_uppercase() {
+        ' ' => [0b000, 0b000, 0b000, 0b000, 0b000],
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        'A' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'B' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'C' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'D' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'E' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'F' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        'G' => [0b011, 0b100, 0b101, 0b101, 0b011],
+        'H' => [0b101, 0b101, 0b111, 0b101, 0b101],
+        'I' => [0b111, 0b010, 0b010, 0b010, 0b111],
+        'J' => [0b001, 0b001, 0b001, 0b101, 0b010],
+        'K' => [0b101, 0b101, 0b110, 0b101, 0b101],
+        'L' => [0b100, 0b100, 0b100, 0b100, 0b111],
+        'M' => [0b101, 0b111, 0b111, 0b101, 0b101],
+        'N' => [0b110, 0b101, 0b101, 0b101, 0b101],
+        'O' => [0b010, 0b101, 0b101, 0b101, 0b010],
+        'P' => [0b110, 0b101, 0b110, 0b100, 0b100],
+        'Q' => [0b010, 0b101, 0b101, 0b110, 0b011],
+        'R' => [0b110, 0b101, 0b110, 0b101, 0b101],
+        'S' => [0b011, 0b100, 0b010, 0b001, 0b110],
+        'T' => [0b111, 0b010, 0b010, 0b010, 0b010],
+        'U' => [0b101, 0b101, 0b101, 0b101, 0b111],
+        'V' => [0b101, 0b101, 0b101, 0b101, 0b010],
+        'W' => [0b101, 0b101, 0b111, 0b111, 0b101],
+        'X' => [0b101, 0b101, 0b010, 0b101, 0b101],
+        'Y' => [0b101, 0b101, 0b010, 0b010, 0b010],
+        'Z' => [0b111, 0b001, 0b010, 0b100, 0b111],
+        '-' => [0b000, 0b000, 0b111, 0b000, 0b000],
+        '+' => [0b000, 0b010, 0b111, 0b010, 0b000],
+        '=' => [0b000, 0b111, 0b000, 0b111, 0b000],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        ',' => [0b000, 0b000, 0b000, 0b010, 0b100],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        '(' => [0b001, 0b010, 0b010, 0b010, 0b001],
+        ')' => [0b100, 0b010, 0b010, 0b010, 0b100],
+        '/' => [0b001, 0b001, 0b010, 0b100, 0b100],
+        '%' => [0b101, 0b001, 0b010, 0b100, 0b101],
+        '<' => [0b001, 0b010, 0b100, 0b010, 0b001],
+        '>' => [0b100, 0b010, 0b001, 0b010, 0b100],
+        '#' => [0b101, 0b111, 0b101, 0b111, 0b101],
+        _ => [0b111, 0b001, 0b010, 0b000, 0b010],
+    }
+}
+
+/// A minimal PNG encoder: 8-bit RGB, compressed with fixed Huffman codes.
+mod png {
+    use std::collections::HashMap;
+
+    const CRC_TABLE: [u32; 256] = {
+        let mut table = [0u32; 256];
+        let mut n = 0;
+        while n < 256 {
+            let mut c = n as u32;
+            let mut k = 0;
+            while k < 8 {
+                c = if c & 1 != 0 {
+                    0xedb88320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                };
+                k += 1;
+            }
+            table[n] = c;
+            n += 1;
+        }
+        table
+    };
+
+    pub fn crc32(bytes: &[u8]) -> u32 {
+        !bytes.iter().fold(!0u32, |crc, &b| {
+            CRC_TABLE[((crc ^ b as u32) & 0xff) as usize] ^ (crc >> 8)
+        })
+    }
+
+    pub fn adler32(bytes: &[u8]) -> u32 {
+        let (a, b) = bytes.iter().fold((1u32, 0u32), |(a, b), &byte| {
+            let a = (a + byte as u32) % 65521;
+            (a, (b + a) % 65521)
+        });
+        (b << 16) | a
+    }
+
+    /// Encodes rows of RGB pixels, each preceded by its filter type.
+    pub fn encode(width: u32, height: u32, raw: &[u8]) -> Vec<u8> {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+        let mut header = Vec::new();
+        header.extend(width.to_be_bytes());
+        header.extend(height.to_be_bytes());
+        // 8 bits per channel, RGB, default compression, filters and no
+        // interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+        chunk(&mut png, b"IHDR", &header);
+
+        // A 32K window with the default compression level, and a check
+        // value making the header a multiple of 31.
+        let mut zlib = vec![0x78, 0x9c];
+        zlib.extend(deflate(raw));
+        zlib.extend(adler32(raw).to_be_bytes());
+        chunk(&mut png, b"IDAT", &zlib);
+
+        chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    fn chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+        png.extend((data.len() as u32).to_be_bytes());
+        let start = png.len();
+        png.extend(kind);
+        png.extend(data);
+        let crc = crc32(&png[start..]);
+        png.extend(crc.to_be_bytes());
+    }
+
+    struct Bits {
+        bytes: Vec<u8>,
+        acc: u64,
+        len: u32,
+    }
+
+    impl Bits {
+        /// Writes the lowest `len` bits of `value`, lowest first.
+        fn write(&mut self, value: u32, len: u32) {
+            self.acc |= (value as u64) << self.len;
+            self.len += len;
+            while self.len >= 8 {
+                self.bytes.push(self.acc as u8);
+                self.acc >>= 8;
+                self.len -= 8;
+            }
+        }
+
+        /// Huffman codes are written from their highest bit.
+        fn write_code(&mut self, code: u32, len: u32) {
+            self.write(code.reverse_bits() >> (32 - len), len);
+        }
+
+        fn finish(mut self) -> Vec<u8> {
+            if self.len > 0 {
+                self.bytes.push(self.acc as u8);
+            }
+            self.bytes
+        }
+    }
+
+    const LENGTH_BASES: [u16; 29] = [
+        3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115,
+        131, 163, 195, 227, 258,
+    ];
+    const LENGTH_EXTRA: [u8; 29] = [
+        0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0,
+    ];
+    const DIST_BASES: [u16; 30] = [
+        1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537,
+        2049, 3073, 4097, 6145, 8193, 12289, 16385, 24577,
+    ];
+    const DIST_EXTRA: [u8; 30] = [
+        0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12,
+        13, 13,
+    ];
+
+    const WINDOW: usize = 32768;
+    const MAX_MATCH: usize = 258;
+    const MAX_CHAIN: usize = 64;
+
+    fn write_literal(bits: &mut Bits, symbol: u16) {
+        match symbol {
+            0..=143 => bits.write_code(0x30 + symbol as u32, 8),
+            144..=255 => bits.write_code(0x190 + symbol as u32 - 144, 9),
+            256..=279 => bits.write_code(symbol as u32 - 256, 7),
+            _ => bits.write_code(0xc0 + symbol as u32 - 280, 8),
+        }
+    }
+
+    fn write_match(bits: &mut Bits, len: usize, dist: usize) {
+        let code = LENGTH_BASES.partition_point(|&base| base as usize <= len) - 1;
+        write_literal(bits, 257 + code as u16);
+        bits.write(
+            (len - LENGTH_BASES[code] as usize) as u32,
+            LENGTH_EXTRA[code] as u32,
+        );
+
+        let code = DIST_BASES.partition_point(|&base| base as usize <= dist) - 1;
+        bits.write_code(code as u32, 5);
+        bits.write(
+            (dist - DIST_BASES[code] as usize) as u32,
+            DIST_EXTRA[code] as u32,
+        );
+    }
+
+    /// A single deflate block with the fixed codes, finding repeats with
+    /// chains of the previous positions of each 3 bytes.
+    pub fn deflate(data: &[u8]) -> Vec<u8> {
+        let mut bits = Bits {
+            bytes: Vec::new(),
+            acc: 0,
+            len: 0,
+        };
+        // The last block, with fixed codes.
+        bits.write(1, 1);
+        bits.write(1, 2);
+
+        let mut heads: HashMap<[u8; 3], usize> = HashMap::new();
+        let mut previous = vec![usize::MAX; data.len()];
+        let insert = |heads: &mut HashMap<[u8; 3], usize>, previous: &mut [usize], pos| {
+            if pos + 3 <= data.len() {
+                let key = [data[pos], data[pos + 1], data[pos + 2]];
+                if let Some(head) = heads.insert(key, pos) {
+                    previous[pos] = head;
+                }
+            }
+        };
+
+        let mut pos = 0;
+        while pos < data.len() {
+            let mut best = (0, 0);
+            if pos + 3 <= data.len() {
+                let key = [data[pos], data[pos + 1], data[pos + 2]];
+                let mut candidate = heads.get(&key).copied();
+                let max_len = MAX_MATCH.min(data.len() - pos);
+
+                for _ in 0..MAX_CHAIN {
+                    let Some(start) = candidate.filter(|&c| pos - c <= WINDOW) else {
+                        break;
+                    };
+                    let len = (0..max_len)
+                        .take_while(|&i| data[start + i] == data[pos + i])
+                        .count();
+                    if len > best.0 {
+                        best = (len, pos - start);
+                    }
+                    if len == max_len {
+                        break;
+                    }
+                    candidate = Some(previous[start]).filter(|&p| p != usize::MAX);
+                }
+            }
+
+            let (len, dist) = best;
+            if len >= 3 {
+                write_match(&mut bits, len, dist);
+                for p in pos..pos + len {
+                    insert(&mut heads, &mut previous, p);
+                }
+                pos += len;
+            } else {
+                write_literal(&mut bits, data[pos] as u16);
+                insert(&mut heads, &mut previous, pos);
+                pos += 1;
+            }
+        }
+
+        write_literal(&mut bits, 256);
+        bits.finish()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Png,
+    Svg,
+}
+
+impl Format {
+    fn extension(self) -> &'static str {
+        match self {
+            Format::Png => "png",
+            Format::Svg => "svg",
+        }
+    }
+}
+
+/// The image options given on the command line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ImageOptions {
+    /// Where to save the pictures. Nothing is saved without it.
+    pub dir: Option<String>,
+    pub format: Format,
+    /// Overrides the size of the cells chosen by each day.
+    pub scale: Option<usize>,
+}
+
+struct Export {
+    options: ImageOptions,
+    /// The first error hit while saving.
+    error: Option<Error>,
+}
+
+thread_local! {
+    static EXPORT: RefCell<Option<Export>> = const { RefCell::new(None) };
+}
+
+/// Saves a picture as `<name>.png` or `<name>.svg` in the directory given
+/// with `--images`, if any. `picture` is only drawn if it's saved.
+pub fn save(name: &str, picture: impl FnOnce() -> Picture) {
+    EXPORT.with_borrow_mut(|export| {
+        let Some(export) = export else {
+            return;
+        };
+        let options = &export.options;
+        let dir = options.dir.as_deref().expect("Exports have a directory");
+
+        let mut picture = picture();
+        if let Some(scale) = options.scale {
+            picture = picture.scale(scale);
+        }
+
+        let path = Path::new(dir).join(format!("{}.{}", name, options.format.extension()));
+        let saved = match options.format {
+            Format::Png => fs::write(&path, picture.to_png()),
+            Format::Svg => fs::write(&path, picture.to_svg()),
+        };
+
+        match saved {
+            Ok(()) => info!("Saved {}", path.display()),
+            Err(err) => {
+                let err = Error::Io(format!("{}: {}", path.display(), err));
+                export.error.get_or_insert(err);
+            }
+        }
+    });
+}
+
+impl ImageOptions {
+    pub const USAGE: &'static str = concat!(
+        "  --images <dir>      Save pictures of the day's states in a directory\n",
+        "  --image-format <f>  png, the default, or svg\n",
+        "  --scale <n>         The size of the pictures' cells in pixels",
+    );
+
+    /// Reads `arg` if it's an image option, taking its value from `rest`.
+    /// Returns `None` for any other argument.
+    pub fn parse_option(
+        &mut self,
+        arg: &str,
+        rest: &mut impl Iterator<Item = String>,
+    ) -> Option<Result<()>> {
+        let mut value = || {
+            rest.next()
+                .ok_or_else(|| usage!("Missing the value after {}", arg))
+        };
+
+        let res = match arg {
+            "--images" => value().map(|dir| self.dir = Some(dir)),
+            "--image-format" => value().and_then(|format| {
+                self.format = match format.as_str() {
+                    "png" => Format::Png,
+                    "svg" => Format::Svg,
+                    _ => return Err(usage!("Unknown image format {}", format)),
+                };
+                Ok(())
+            }),
+            "--scale" => value().and_then(|scale| {
+                let scale = scale
+                    .parse()
+                    .ok()
+                    .filter(|&n| n > 0)
+                    .ok_or_else(|| usage!("--scale needs a positive number, got {}", scale))?;
+                self.scale = Some(scale);
+                Ok(())
+            }),
+            _ => return None,
+        };
+
+        Some(res)
+    }
+
+    /// Runs `f`, saving the pictures it draws if a directory was given.
+    pub fn export<T>(&self, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        let Some(dir) = &self.dir else {
+            return f();
+        };
+
+        fs::create_dir_all(dir).map_err(|err| Error::Io(format!("{}: {}", dir, err)))?;
+        EXPORT.with_borrow_mut(|export| {
+            *export = Some(Export {
+                options: self.clone(),
+                error: None,
+            })
+        });
+
+        let res = f();
+        let export = EXPORT
+            .with_borrow_mut(Option::take)
+            .expect("The export is only taken here");
+
+        match export.error {
+            Some(err) => Err(err),
+            None => res,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{png, ImageOptions, Picture, Rgb};
+    use crate::{coord::Coord, grid::Grid};
+
+    const RED: Rgb = Rgb(255, 0, 0);
+
+    fn picture() -> Picture {
+        let grid = Grid::from_fn(3, 2, |coord| coord.x == coord.y);
+        Picture::from_grid(&grid, |_, &set| set.then_some(RED)).scale(2)
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(png::crc32(b"IEND"), 0xae426082);
+        assert_eq!(png::adler32(b"Wikipedia"), 0x11e60398);
+    }
+
+    #[test]
+    fn pixels() {
+        let pixels = picture().pixels();
+        let row = |y: usize| &pixels[y * 6..(y + 1) * 6];
+
+        assert_eq!(pixels.len(), 6 * 4);
+        assert_eq!(
+            row(0),
+            [RED, RED, Rgb::WHITE, Rgb::WHITE, Rgb::WHITE, Rgb::WHITE]
+        );
+        assert_eq!(
+            row(3),
+            [Rgb::WHITE, Rgb::WHITE, RED, RED, Rgb::WHITE, Rgb::WHITE]
+        );
+    }
+
+    #[test]
+    fn overlays() {
+        let picture = picture()
+            .path([Coord::new(0, 1), Coord::new(2, 1)], Rgb::BLACK)
+            .marker(Coord::new(2, 0), Rgb::BLACK)
+            .legend("Rock", RED);
+        let (width, height) = picture.size();
+        let pixels = picture.pixels();
+
+        assert_eq!((width, height), (50, 18));
+        assert_eq!(pixels[3 * width + 3], Rgb::BLACK);
+        assert_eq!(pixels[width + 5], Rgb::BLACK);
+
+        let svg = picture.to_svg();
+        assert!(svg.contains(r##"<rect x="0" y="0" width="2" height="2" fill="#ff0000"/>"##));
+        assert!(svg.contains(r#"<polyline points="1,3 5,3""#));
+        assert!(svg.contains(">Rock</text>"));
+    }
+
+    #[test]
+    fn png_chunks() {
+        let png = picture().to_png();
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], [0, 0, 0, 6, 0, 0, 0, 4]);
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+
+    #[test]
+    fn options() {
+        let mut options = ImageOptions::default();
+        let mut args = ["out", "svg", "0", "bmp"].map(String::from).into_iter();
+
+        assert_eq!(options.parse_option("--images", &mut args), Some(Ok(())));
+        assert_eq!(
+            options.parse_option("--image-format", &mut args),
+            Some(Ok(()))
+        );
+        assert!(options.parse_option("--scale", &mut args).unwrap().is_err());
+        assert!(options
+            .parse_option("--image-format", &mut args)
+            .unwrap()
+            .is_err());
+        assert_eq!(options.parse_option("--record", &mut args), None);
+        assert_eq!(options.dir.as_deref(), Some("out"));
+    }
+}
@@ -0,0 +1,68 @@
+//! Where a solution reads its puzzle input from, chosen on the command line.
+
+use std::{
+    borrow::Cow,
+    fs,
+    io::{stdin, Read},
+};
+
+use crate::{usage, Error, Result};
+
+/// The inputs compiled into a day's binary.
+#[derive(Clone, Copy)]
+pub struct Bundled {
+    /// The puzzle input, used when nothing else is asked for.
+    pub input: &'static str,
+    /// The example from the puzzle text.
+    pub sample: &'static str,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Source {
+    #[default]
+    Bundled,
+    Sample,
+    Stdin,
+    File(String),
+}
+
+impl Source {
+    pub const USAGE: &'static str = concat!(
+        "  --input <path>  Read the input from a file, or from stdin if the path is -\n",
+        "  -               Same as --input -\n",
+        "  --sample        Use the example from the puzzle text",
+    );
+
+    /// Reads `arg` if it's an input option, taking its value from `rest`.
+    /// Returns `None` for any other argument.
+    pub fn parse_option(
+        arg: &str,
+        rest: &mut impl Iterator<Item = String>,
+    ) -> Option<Result<Source>> {
+        match arg {
+            "-" => Some(Ok(Source::Stdin)),
+            "--sample" => Some(Ok(Source::Sample)),
+            "--input" => Some(match rest.next().as_deref() {
+                Some("-") => Ok(Source::Stdin),
+                Some(path) => Ok(Source::File(path.to_string())),
+                None => Err(usage!("Missing the path after --input")),
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn read(&self, bundled: Bundled) -> Result<Cow<'static, str>> {
+        Ok(match self {
+            Source::Bundled => Cow::Borrowed(bundled.input),
+            Source::Sample => Cow::Borrowed(bundled.sample),
+            Source::Stdin => {
+                let mut input = String::new();
+                stdin().read_to_string(&mut input)?;
+                Cow::Owned(input)
+            }
+            Source::File(path) => Cow::Owned(
+                fs::read_to_string(path).map_err(|err| Error::Io(format!("{}: {}", path, err)))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,262 @@
+use std::{fmt::Display, iter::Copied, slice};
+
+/// An inclusive range of integers, empty if `start > end`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, dbg_pls::DebugPls)]
+pub struct Interval {
+    pub start: isize,
+    pub end: isize,
+}
+
+impl Interval {
+    pub const fn new(start: isize, end: isize) -> Interval {
+        Interval { start, end }
+    }
+
+    pub const fn point(x: isize) -> Interval {
+        Interval::new(x, x)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.start > self.end
+    }
+
+    pub fn len(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            self.end.abs_diff(self.start) + 1
+        }
+    }
+
+    pub fn contains(&self, x: isize) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        other.is_empty() || (self.start <= other.start && other.end <= self.end)
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    pub fn intersection(&self, other: &Interval) -> Interval {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}..={}", self.start, self.end)
+    }
+}
+
+/// A set of integers stored as sorted, disjoint and non-adjacent intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq, dbg_pls::DebugPls)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /// The intervals of the set in increasing order.
+    pub fn iter(&self) -> Copied<slice::Iter<'_, Interval>> {
+        self.intervals.iter().copied()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The number of integers in the set.
+    pub fn len(&self) -> usize {
+        self.intervals.iter().map(Interval::len).sum()
+    }
+
+    pub fn contains(&self, x: isize) -> bool {
+        let idx = self.intervals.partition_point(|i| i.end < x);
+        self.intervals.get(idx).is_some_and(|i| i.contains(x))
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        // Touching intervals are merged too.
+        let lo = self
+            .intervals
+            .partition_point(|i| i.end < interval.start.saturating_sub(1));
+        let hi = self
+            .intervals
+            .partition_point(|i| i.start <= interval.end.saturating_add(1));
+
+        let mut merged = interval;
+        if lo < hi {
+            merged.start = merged.start.min(self.intervals[lo].start);
+            merged.end = merged.end.max(self.intervals[hi - 1].end);
+        }
+
+        self.intervals.splice(lo..hi, [merged]);
+    }
+
+    pub fn remove(&mut self, interval: Interval) {
+        if interval.is_empty() {
+            return;
+        }
+
+        let lo = self.intervals.partition_point(|i| i.end < interval.start);
+        let hi = self.intervals.partition_point(|i| i.start <= interval.end);
+        if lo == hi {
+            return;
+        }
+
+        let (first, last) = (self.intervals[lo], self.intervals[hi - 1]);
+        let left = Interval::new(first.start, interval.start - 1);
+        let right = Interval::new(interval.end + 1, last.end);
+
+        self.intervals
+            .splice(lo..hi, [left, right].into_iter().filter(|i| !i.is_empty()));
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        other.iter().for_each(|interval| union.insert(interval));
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut a, mut b) = (self.intervals.iter(), other.intervals.iter());
+        let (mut curr_a, mut curr_b) = (a.next(), b.next());
+
+        while let (Some(ia), Some(ib)) = (curr_a, curr_b) {
+            let common = ia.intersection(ib);
+            if !common.is_empty() {
+                intervals.push(common);
+            }
+
+            if ia.end < ib.end {
+                curr_a = a.next();
+            } else {
+                curr_b = b.next();
+            }
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// Everything inside `within` that is not in the set.
+    pub fn complement(&self, within: Interval) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let mut next = within.start;
+
+        for interval in self.iter() {
+            if interval.end < within.start {
+                continue;
+            }
+
+            if interval.start > within.end {
+                break;
+            }
+
+            if interval.start > next {
+                intervals.push(Interval::new(next, interval.start - 1));
+            }
+
+            next = interval.end.saturating_add(1);
+        }
+
+        if next <= within.end {
+            intervals.push(Interval::new(next, within.end));
+        }
+
+        IntervalSet { intervals }
+    }
+
+    /// The holes between the first and the last interval of the set.
+    pub fn gaps(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals
+            .windows(2)
+            .map(|w| Interval::new(w[0].end + 1, w[1].start - 1))
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Interval> for IntervalSet {
+    fn extend<I: IntoIterator<Item = Interval>>(&mut self, iter: I) {
+        iter.into_iter().for_each(|interval| self.insert(interval));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Interval, IntervalSet};
+
+    fn set(intervals: &[(isize, isize)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|&(start, end)| Interval::new(start, end))
+            .collect()
+    }
+
+    fn intervals(set: &IntervalSet) -> Vec<(isize, isize)> {
+        set.iter().map(|i| (i.start, i.end)).collect()
+    }
+
+    #[test]
+    fn insert_merges() {
+        let set = set(&[(5, 7), (0, 2), (3, 3), (10, 12), (6, 9), (20, 19)]);
+
+        assert_eq!(intervals(&set), [(0, 3), (5, 12)]);
+        assert_eq!(set.len(), 12);
+        assert!(set.contains(3));
+        assert!(!set.contains(4));
+        assert!(!set.contains(13));
+    }
+
+    #[test]
+    fn remove_splits() {
+        let mut set = set(&[(0, 10), (15, 20)]);
+        set.remove(Interval::new(3, 4));
+        set.remove(Interval::new(9, 16));
+        set.remove(Interval::point(20));
+
+        assert_eq!(intervals(&set), [(0, 2), (5, 8), (17, 19)]);
+    }
+
+    #[test]
+    fn set_operations() {
+        let a = set(&[(0, 5), (10, 15)]);
+        let b = set(&[(3, 11), (14, 20)]);
+
+        assert_eq!(intervals(&a.union(&b)), [(0, 20)]);
+        assert_eq!(intervals(&a.intersection(&b)), [(3, 5), (10, 11), (14, 15)]);
+        assert_eq!(
+            intervals(&a.complement(Interval::new(-2, 12))),
+            [(-2, -1), (6, 9)]
+        );
+        assert_eq!(a.gaps().collect::<Vec<_>>(), [Interval::new(6, 9)]);
+    }
+
+    #[test]
+    fn interval() {
+        let a = Interval::new(2, 8);
+
+        assert!(a.contains_interval(&Interval::new(3, 8)));
+        assert!(!a.contains_interval(&Interval::new(1, 3)));
+        assert!(a.overlaps(&Interval::new(8, 9)));
+        assert!(!a.overlaps(&Interval::new(9, 9)));
+        assert_eq!(Interval::new(3, 2).len(), 0);
+    }
+}
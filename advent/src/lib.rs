@@ -1,9 +1,29 @@
 pub mod bounds;
 pub mod coord;
 pub mod coord3;
+pub mod cycle;
 pub mod diagonal_iterable;
+pub mod direction;
+pub mod error;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod intervals;
+pub mod log;
+pub mod memory;
+pub mod params;
 pub mod parsers;
+pub mod point;
+pub mod random;
+pub mod record;
+pub mod report;
+pub mod search;
 pub mod side_effect;
+pub mod solution;
+pub mod sparse_grid;
+pub mod step;
+
+pub use error::{Error, Result};
 
 #[macro_export]
 macro_rules! vec2d {
@@ -0,0 +1,248 @@
+//! Leveled diagnostics on stderr, off unless asked for.
+//!
+//! Days log with [`info!`](crate::info!), [`debug!`](crate::debug!) and
+//! [`trace!`](crate::trace!), each message tagged with its module. `-v`
+//! shows the info messages, `-vv` the debug ones too and `-vvv` everything.
+//! The `AOC_LOG` environment variable picks levels per target, the most
+//! specific one winning:
+//!
+//! ```text
+//! AOC_LOG=info,day16=debug,day22::part2=trace
+//! ```
+
+use std::{
+    fmt::{self, Arguments},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+use crate::{usage, Error, Result};
+
+pub const ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// The level shown by a number of `-v`s, if any.
+    pub fn from_verbosity(verbosity: u8) -> Option<Level> {
+        match verbosity {
+            0 => None,
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            _ => Some(Level::Trace),
+        }
+    }
+}
+
+impl FromStr for Level {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Level> {
+        match s.to_ascii_lowercase().as_str() {
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(usage!("Unknown log level {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        })
+    }
+}
+
+/// The most verbose level shown for each target, and for every other one.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Filter {
+    default: Option<Level>,
+    targets: Vec<(String, Option<Level>)>,
+}
+
+impl Filter {
+    pub const fn new() -> Filter {
+        Filter {
+            default: None,
+            targets: Vec::new(),
+        }
+    }
+
+    /// Parses comma separated directives: a level for every target, or
+    /// `target=level`, where the level can be `off`.
+    pub fn parse(directives: &str) -> Result<Filter> {
+        let level = |level: &str| match level.trim() {
+            "off" => Ok(None),
+            level => level.parse().map(Some),
+        };
+
+        let mut filter = Filter::new();
+        for directive in directives.split(',').filter(|d| !d.trim().is_empty()) {
+            match directive.split_once('=') {
+                Some((target, lvl)) => filter
+                    .targets
+                    .push((target.trim().to_string(), level(lvl)?)),
+                None => filter.default = level(directive)?,
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Shows at least `level` for every target without its own directive.
+    pub fn at_least(mut self, level: Option<Level>) -> Filter {
+        self.default = self.default.max(level);
+        self
+    }
+
+    fn level(&self, target: &str) -> Option<Level> {
+        let is_within = |prefix: &str| {
+            target
+                .strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+        };
+
+        self.targets
+            .iter()
+            .filter(|(prefix, _)| is_within(prefix))
+            .max_by_key(|(prefix, _)| prefix.len())
+            .map_or(self.default, |&(_, level)| level)
+    }
+
+    /// The most verbose level shown for any target.
+    fn max_level(&self) -> Option<Level> {
+        self.targets
+            .iter()
+            .map(|&(_, level)| level)
+            .fold(self.default, Option::max)
+    }
+}
+
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new());
+/// The most verbose level of the filter, to skip it quickly when it's off.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_filter(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level().map_or(0, |l| l as u8), Ordering::Relaxed);
+    *FILTER.write().unwrap_or_else(|err| err.into_inner()) = filter;
+}
+
+/// Shows the levels asked for in `AOC_LOG`, and at least the one given by
+/// `verbosity`.
+pub fn init(verbosity: u8) -> Result<()> {
+    let filter = match std::env::var(ENV_VAR) {
+        Ok(directives) => {
+            Filter::parse(&directives).map_err(|err| usage!("Invalid {}: {}", ENV_VAR, err))?
+        }
+        Err(_) => Filter::new(),
+    };
+
+    set_filter(filter.at_least(Level::from_verbosity(verbosity)));
+    Ok(())
+}
+
+pub fn enabled(level: Level, target: &str) -> bool {
+    level as u8 <= MAX_LEVEL.load(Ordering::Relaxed)
+        && FILTER
+            .read()
+            .unwrap_or_else(|err| err.into_inner())
+            .level(target)
+            .is_some_and(|max| level <= max)
+}
+
+/// Writes a message, once [`enabled`] checked that it's shown.
+pub fn write(level: Level, target: &str, message: Arguments) {
+    eprintln!("[{} {}] {}", level, target, message);
+}
+
+/// Reads `arg` if it's a verbosity option, adding it to `verbosity`.
+/// Returns `false` for any other argument.
+pub fn parse_verbosity(arg: &str, verbosity: &mut u8) -> bool {
+    match arg.strip_prefix('-') {
+        Some(vs) if !vs.is_empty() && vs.chars().all(|c| c == 'v') => {
+            *verbosity = verbosity.saturating_add(vs.len() as u8);
+            true
+        }
+        _ => false,
+    }
+}
+
+pub const USAGE: &str = concat!(
+    "  -v, -vv, -vvv       Show info, debug or trace messages on stderr\n",
+    "                      (or per target with AOC_LOG=info,day16=debug)",
+);
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_verbosity, Filter, Level};
+
+    #[test]
+    fn filter() {
+        let filter = Filter::parse("info, day16=debug, day22::part2=trace, day11=off").unwrap();
+
+        assert_eq!(filter.level("day01"), Some(Level::Info));
+        assert_eq!(filter.level("day16"), Some(Level::Debug));
+        assert_eq!(filter.level("day160"), Some(Level::Info));
+        assert_eq!(filter.level("day22"), Some(Level::Info));
+        assert_eq!(filter.level("day22::part2"), Some(Level::Trace));
+        assert_eq!(filter.level("day11::parser"), None);
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+
+        let verbose = Filter::parse("day11=off")
+            .unwrap()
+            .at_least(Some(Level::Debug));
+        assert_eq!(verbose.level("day01"), Some(Level::Debug));
+        assert_eq!(verbose.level("day11"), None);
+
+        assert_eq!(Filter::parse(""), Ok(Filter::new()));
+        assert!(Filter::parse("loud").is_err());
+        assert!(Filter::parse("day01=loud").is_err());
+    }
+
+    #[test]
+    fn verbosity() {
+        let mut verbosity = 0;
+        assert!(parse_verbosity("-v", &mut verbosity));
+        assert!(parse_verbosity("-vv", &mut verbosity));
+        assert!(!parse_verbosity("-", &mut verbosity));
+        assert!(!parse_verbosity("--verbose", &mut verbosity));
+        assert_eq!(verbosity, 3);
+        assert_eq!(Level::from_verbosity(verbosity), Some(Level::Trace));
+    }
+}
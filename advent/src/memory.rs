@@ -0,0 +1,169 @@
+//! Accounting of what the solvers allocate. A binary opts in by installing
+//! the counting allocator:
+//!
+//! ```ignore
+//! #[global_allocator]
+//! static ALLOCATOR: advent::memory::Counting = advent::memory::Counting;
+//! ```
+//!
+//! It only counts while [`measure`] runs, so it costs next to nothing the
+//! rest of the time. It counts every thread then, including those a solver
+//! spawns, so only one measure can run at a time.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::Display,
+    sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering},
+};
+
+static MEASURING: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+/// The bytes live on top of those that were when measuring started,
+/// negative once some of those were freed.
+static LIVE: AtomicIsize = AtomicIsize::new(0);
+static PEAK: AtomicIsize = AtomicIsize::new(0);
+
+/// Counts an allocation of `size` bytes, `freed` bytes being given back
+/// along with it when reallocating.
+fn count(size: usize, freed: usize) {
+    if MEASURING.load(Ordering::Relaxed) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(size, Ordering::Relaxed);
+
+        let grown = size as isize - freed as isize;
+        let live = LIVE.fetch_add(grown, Ordering::Relaxed) + grown;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+fn count_free(size: usize) {
+    if MEASURING.load(Ordering::Relaxed) {
+        LIVE.fetch_sub(size as isize, Ordering::Relaxed);
+    }
+}
+
+/// The system allocator, counting the allocations made while measuring.
+pub struct Counting;
+
+// SAFETY: every call is forwarded to `System` as is, the counting around it
+// doesn't allocate.
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(layout.size(), 0);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count_free(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(new_size, layout.size());
+        }
+        new_ptr
+    }
+}
+
+/// What was allocated while measuring. Reallocations count as allocations
+/// of their new size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    pub bytes: usize,
+    /// The most bytes live at once, not counting those already live before.
+    pub peak_bytes: usize,
+}
+
+/// Each stage, like `parse` or `part1`, with what it allocated.
+pub type Memory = Vec<(&'static str, Usage)>;
+
+/// Runs `f`, counting what it allocates, on any thread. Everything is zero
+/// unless the binary installed [`Counting`]. Measures can't be nested or run
+/// concurrently, as they would count each other's allocations.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    ALLOCATIONS.store(0, Ordering::Relaxed);
+    BYTES.store(0, Ordering::Relaxed);
+    LIVE.store(0, Ordering::Relaxed);
+    PEAK.store(0, Ordering::Relaxed);
+    MEASURING.store(true, Ordering::SeqCst);
+    let res = f();
+    MEASURING.store(false, Ordering::SeqCst);
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed),
+        bytes: BYTES.load(Ordering::Relaxed),
+        peak_bytes: PEAK.load(Ordering::Relaxed).max(0) as usize,
+    };
+
+    (res, usage)
+}
+
+fn bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024. && unit < UNITS.len() - 1 {
+        size /= 1024.;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} B", bytes),
+        _ => format!("{:.1} {}", size, UNITS[unit]),
+    }
+}
+
+/// The stages' usage as a table.
+pub struct Table<'a>(pub &'a [(&'static str, Usage)]);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{:<6} {:>11} {:>11} {:>11}",
+            "Stage", "Allocations", "Bytes", "Peak"
+        )?;
+
+        for (stage, usage) in self.0 {
+            writeln!(
+                f,
+                "{:<6} {:>11} {:>11} {:>11}",
+                stage,
+                usage.allocations,
+                bytes(usage.bytes),
+                bytes(usage.peak_bytes)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::bytes;
+
+    #[test]
+    fn sizes() {
+        assert_eq!(bytes(0), "0 B");
+        assert_eq!(bytes(1023), "1023 B");
+        assert_eq!(bytes(1536), "1.5 KiB");
+        assert_eq!(bytes(3 << 30), "3.0 GiB");
+    }
+}
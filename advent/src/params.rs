@@ -0,0 +1,360 @@
+//! Puzzle parameters, like the number of rounds to play, with defaults
+//! that can be overridden without recompiling.
+//!
+//! A day declares its parameters with [`params!`](crate::params!). They're
+//! overridden with `--param name=value` on the command line, or from an
+//! INI-style config file given with `--config`:
+//!
+//! ```text
+//! # Comments start with '#' or ';'
+//! [day11]
+//! rounds = 500
+//! relief = 2
+//! ```
+//!
+//! Command line overrides win over the config file.
+
+use std::{cell::RefCell, collections::BTreeMap, fs, ops::Deref, str::FromStr};
+
+use crate::{coord::Coord, usage, Error, Result};
+
+/// A value that can be given as a parameter.
+pub trait Param: Sized {
+    fn parse_param(value: &str) -> Option<Self>;
+
+    /// The value as it would be given, or `None` if it's unset.
+    fn show_param(&self) -> Option<String>;
+}
+
+macro_rules! from_str_params {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl Param for $ty {
+                fn parse_param(value: &str) -> Option<Self> {
+                    <$ty>::from_str(value).ok()
+                }
+
+                fn show_param(&self) -> Option<String> {
+                    Some(self.to_string())
+                }
+            }
+        )*
+    };
+}
+
+from_str_params!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, bool, String);
+
+/// A coord written as `x,y`.
+impl Param for Coord {
+    fn parse_param(value: &str) -> Option<Self> {
+        let (x, y) = value.split_once(',')?;
+        Some(Coord::new(x.trim().parse().ok()?, y.trim().parse().ok()?))
+    }
+
+    fn show_param(&self) -> Option<String> {
+        Some(format!("{},{}", self.x, self.y))
+    }
+}
+
+/// Unset by default, so that the day can pick a value from its input.
+impl<T: Param> Param for Option<T> {
+    fn parse_param(value: &str) -> Option<Self> {
+        T::parse_param(value).map(Some)
+    }
+
+    fn show_param(&self) -> Option<String> {
+        self.as_ref().and_then(T::show_param)
+    }
+}
+
+/// A day's parameters. Implemented by [`params!`](crate::params!).
+pub trait Params: Default {
+    /// The config file section holding the parameters, like `day11`.
+    const SECTION: &'static str;
+
+    fn set(&mut self, name: &str, value: &str) -> Result<()>;
+
+    /// Each parameter's name and value, `None` for those left unset.
+    fn values(&self) -> Vec<(&'static str, Option<String>)>;
+}
+
+/// Declares a parameter struct, with the default of each field and the
+/// config file section it's read from.
+#[macro_export]
+macro_rules! params {
+    (
+        $( #[$meta:meta] )*
+        $vis:vis struct $name:ident in $section:literal {
+            $(
+                $( #[$field_meta:meta] )*
+                $field:ident : $ty:ty = $default:expr
+            ),* $(,)?
+        }
+    ) => {
+        $( #[$meta] )*
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        $vis struct $name {
+            $(
+                $( #[$field_meta] )*
+                pub $field: $ty,
+            )*
+        }
+
+        impl Default for $name {
+            fn default() -> Self {
+                $name {
+                    $( $field: $default, )*
+                }
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            const SECTION: &'static str = $section;
+
+            fn set(&mut self, name: &str, value: &str) -> $crate::Result<()> {
+                match name {
+                    $(
+                        stringify!($field) => {
+                            self.$field = $crate::params::Param::parse_param(value)
+                                .ok_or_else(|| {
+                                    $crate::usage!("Invalid value for {}: {}", name, value)
+                                })?;
+                        }
+                    )*
+                    _ => return Err($crate::usage!("Unknown parameter {}", name)),
+                }
+
+                Ok(())
+            }
+
+            fn values(&self) -> Vec<(&'static str, Option<String>)> {
+                vec![
+                    $(
+                        (
+                            stringify!($field),
+                            $crate::params::Param::show_param(&self.$field),
+                        ),
+                    )*
+                ]
+            }
+        }
+    };
+}
+
+/// The parameters a day was solved with, by name.
+pub type Used = Vec<(&'static str, Option<String>)>;
+
+thread_local! {
+    static USED: RefCell<Option<Used>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, and returns the parameters built by [`Overrides::params`] along
+/// with its result.
+pub fn track<T>(f: impl FnOnce() -> T) -> (T, Used) {
+    let outer = USED.replace(Some(Vec::new()));
+    let res = f();
+    let used = USED.replace(outer).unwrap_or_default();
+    (res, used)
+}
+
+/// A day's parsed input, along with its parameters.
+#[derive(Clone, Debug)]
+pub struct WithParams<T, P> {
+    pub input: T,
+    pub params: P,
+}
+
+impl<T, P> Deref for WithParams<T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.input
+    }
+}
+
+/// The parameters overridden on the command line and in the config file,
+/// not yet checked against any day's parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Overrides {
+    args: Vec<(String, String)>,
+    config: BTreeMap<String, Vec<(String, String)>>,
+}
+
+impl Overrides {
+    pub const USAGE: &'static str = concat!(
+        "  --param <name>=<value>  Override one of the day's parameters\n",
+        "  --config <path>         Read parameters from the [dayNN] sections of a file",
+    );
+
+    /// Reads `arg` if it's a parameter option, taking its value from `rest`.
+    /// Returns `None` for any other argument.
+    pub fn parse_option(
+        &mut self,
+        arg: &str,
+        rest: &mut impl Iterator<Item = String>,
+    ) -> Option<Result<()>> {
+        let mut value = || {
+            rest.next()
+                .ok_or_else(|| usage!("Missing the value after {}", arg))
+        };
+
+        match arg {
+            "--param" => Some(value().and_then(|param| self.add_arg(&param))),
+            "--config" => Some(value().and_then(|path| {
+                let config = fs::read_to_string(&path)
+                    .map_err(|err| Error::Io(format!("{}: {}", path, err)))?;
+                self.add_config(&config)
+            })),
+            _ => None,
+        }
+    }
+
+    /// Adds a `name=value` override.
+    pub fn add_arg(&mut self, param: &str) -> Result<()> {
+        let (name, value) = param
+            .split_once('=')
+            .ok_or_else(|| usage!("Expected name=value, got {}", param))?;
+
+        self.args
+            .push((name.trim().to_string(), value.trim().to_string()));
+        Ok(())
+    }
+
+    /// Adds the overrides of an INI-style config file.
+    pub fn add_config(&mut self, config: &str) -> Result<()> {
+        let mut section = None;
+
+        for (idx, line) in config.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(['#', ';']) {
+                continue;
+            }
+
+            if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                section = Some(name.trim().to_string());
+                continue;
+            }
+
+            let (Some(section), Some((name, value))) = (&section, line.split_once('=')) else {
+                return Err(usage!("Line {} of the config isn't in a section", idx + 1));
+            };
+
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|v| v.strip_suffix('"'))
+                .unwrap_or(value);
+
+            self.config
+                .entry(section.clone())
+                .or_default()
+                .push((name.trim().to_string(), value.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Builds the parameters of a day: the defaults, then the day's config
+    /// section, then the command line.
+    pub fn params<P: Params>(&self) -> Result<P> {
+        let mut params = P::default();
+
+        let config = self.config.get(P::SECTION).into_iter().flatten();
+        for (name, value) in config.chain(&self.args) {
+            params.set(name, value)?;
+        }
+
+        USED.with_borrow_mut(|used| {
+            if let Some(used) = used {
+                used.extend(params.values());
+            }
+        });
+        Ok(params)
+    }
+
+    /// Checks that nothing was overridden on the command line, for a day
+    /// without parameters. Its config section, if any, is ignored.
+    pub fn ensure_no_args(&self) -> Result<()> {
+        match self.args.first() {
+            Some((name, _)) => Err(usage!("Unknown parameter {}", name)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn has_args(&self) -> bool {
+        !self.args.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{track, Overrides, Params};
+
+    crate::params! {
+        struct TestParams in "day00" {
+            rounds: usize = 20,
+            key: Option<i64> = None,
+            name: String = "root".to_string(),
+        }
+    }
+
+    #[test]
+    fn defaults() {
+        let params: TestParams = Overrides::default().params().unwrap();
+        assert_eq!(params, TestParams::default());
+        assert_eq!(params.rounds, 20);
+        assert_eq!(TestParams::SECTION, "day00");
+    }
+
+    #[test]
+    fn overrides() {
+        let mut overrides = Overrides::default();
+        overrides
+            .add_config("# Parameters\n[day00]\nrounds = 500\nname = \"humn\"\n[day01]\nkey = 3\n")
+            .unwrap();
+        overrides.add_arg("key=-7").unwrap();
+        overrides.add_arg("rounds=5").unwrap();
+
+        assert_eq!(
+            overrides.params(),
+            Ok(TestParams {
+                rounds: 5,
+                key: Some(-7),
+                name: "humn".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn tracked() {
+        let mut overrides = Overrides::default();
+        overrides.add_arg("rounds=5").unwrap();
+
+        let (params, used) = track(|| overrides.params::<TestParams>());
+        assert!(params.is_ok());
+        assert_eq!(
+            used,
+            vec![
+                ("rounds", Some("5".to_string())),
+                ("key", None),
+                ("name", Some("root".to_string())),
+            ]
+        );
+        assert_eq!(track(|| ()).1, vec![]);
+    }
+
+    #[test]
+    fn errors() {
+        let mut overrides = Overrides::default();
+        assert!(overrides.add_arg("rounds").is_err());
+        assert!(overrides.add_config("rounds = 3").is_err());
+
+        overrides.add_arg("rounds=many").unwrap();
+        assert!(overrides.params::<TestParams>().is_err());
+
+        let mut overrides = Overrides::default();
+        overrides.add_arg("minutes=3").unwrap();
+        assert!(overrides.params::<TestParams>().is_err());
+        assert!(overrides.ensure_no_args().is_err());
+    }
+}
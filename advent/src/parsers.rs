@@ -1,16 +1,272 @@
+use std::{fmt::Display, str::FromStr};
+
 use nom::{
     branch::alt,
-    character::{complete::char, complete::digit1},
-    combinator::{map, map_res, opt, value},
-    IResult,
+    bytes::complete::tag,
+    character::complete::{anychar, char, digit1, line_ending, multispace0, space0, space1},
+    combinator::{all_consuming, map, map_opt, map_res, opt, recognize},
+    error::ErrorKind,
+    multi::{many1, separated_list1},
+    sequence::{delimited, pair, preceded, separated_pair, terminated, tuple},
+    Finish, IResult, Parser,
 };
 
-pub fn parse_signed(input: &str) -> IResult<&str, isize> {
-    let minus = value(-1isize, char('-'));
-    let plus = value(1isize, opt(char('+')));
-    let (input, mul) = alt((minus, plus))(input)?;
+use crate::{coord::Coord, coord3::Coord3, grid::Grid};
+
+pub fn parse_unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// An integer with an optional `+` or `-` sign.
+pub fn parse_signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(
+        recognize(pair(opt(alt((char('-'), char('+')))), digit1)),
+        str::parse,
+    )(input)
+}
+
+/// One cell per character and one row per line, all rows the same width.
+/// Stops before the first line that isn't entirely made of characters
+/// accepted by `cell`.
+pub fn parse_char_grid<'a, T, F>(cell: F) -> impl FnMut(&'a str) -> IResult<&'a str, Grid<T>>
+where
+    F: Fn(char) -> Option<T> + Copy,
+{
+    move |input| {
+        let row = many1(map_opt(anychar, cell));
+        let (rest, rows) = separated_list1(line_ending, row)(input)?;
+
+        let width = rows[0].len();
+        if rows.iter().any(|row| row.len() != width) {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                input,
+                ErrorKind::Verify,
+            )));
+        }
+
+        let height = rows.len();
+        let cells = rows.into_iter().flatten().collect();
+        Ok((rest, Grid::from_cells(width, height, cells)))
+    }
+}
+
+/// Blocks separated by a single empty line.
+pub fn parse_blocks<'a, O, F>(block: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    separated_list1(pair(line_ending, line_ending), block)
+}
+
+/// Items separated by commas, spaces or both, like `1, 2,3 4`.
+pub fn parse_list<'a, O, F>(item: F) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<O>>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    let comma = delimited(space0, char(','), space0);
+    separated_list1(alt((comma, map(space1, |_| ','))), item)
+}
+
+/// A `key=value` pair, returning the value.
+pub fn parse_key_value<'a, O, F>(
+    key: &'static str,
+    value: F,
+) -> impl FnMut(&'a str) -> IResult<&'a str, O>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    preceded(pair(tag(key), char('=')), value)
+}
+
+/// A coord written as `x=1, y=-2`.
+pub fn parse_xy(input: &str) -> IResult<&str, Coord> {
+    map(
+        separated_pair(
+            parse_key_value("x", parse_signed),
+            tag(", "),
+            parse_key_value("y", parse_signed),
+        ),
+        |(x, y)| Coord::new(x, y),
+    )(input)
+}
+
+/// A coord written as `x=1, y=-2, z=3`.
+pub fn parse_xyz(input: &str) -> IResult<&str, Coord3> {
+    map(
+        tuple((
+            parse_key_value("x", parse_signed),
+            preceded(tag(", "), parse_key_value("y", parse_signed)),
+            preceded(tag(", "), parse_key_value("z", parse_signed)),
+        )),
+        |(x, y, z)| Coord3::new(x, y, z),
+    )(input)
+}
+
+/// Where and why parsing failed. Lines and columns start at 1.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The whole line the error is on.
+    pub snippet: String,
+    pub kind: ErrorKind,
+}
+
+impl ParseError {
+    /// Locates `remaining`, which must be a suffix of `input`.
+    pub fn new(input: &str, remaining: &str, kind: ErrorKind) -> ParseError {
+        let offset = input.len() - remaining.len();
+        let before = &input[..offset];
+
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |idx| offset + idx);
+
+        ParseError {
+            line: before.matches('\n').count() + 1,
+            column: input[line_start..offset].chars().count() + 1,
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_string(),
+            kind,
+        }
+    }
+
+    /// What went wrong. nom reports input left over where it expected the
+    /// end as `Eof`, like running out of input, so that one is told apart
+    /// by there being input left at the error.
+    pub fn description(&self) -> &str {
+        match &self.kind {
+            ErrorKind::Eof if self.column <= self.snippet.chars().count() => {
+                "Unexpected trailing input"
+            }
+            kind => kind.description(),
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} at line {}, column {}:",
+            self.description(),
+            self.line,
+            self.column
+        )?;
+        writeln!(f, "  {}", self.snippet)?;
+        write!(f, "  {:>width$}", "^", width = self.column)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Runs `parser` on the whole input, allowing only trailing whitespace to
+/// be left over.
+pub fn parse_all<'a, O, F>(parser: F, input: &'a str) -> Result<O, ParseError>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    all_consuming(terminated(parser, multispace0))(input)
+        .finish()
+        .map(|(_, output)| output)
+        .map_err(|err| ParseError::new(input, err.input, err.code))
+}
+
+/// Runs `parser` on every line of `input`. Errors are located within the
+/// whole input, not just the line.
+pub fn parse_lines<'a, O, F>(mut parser: F, input: &'a str) -> Result<Vec<O>, ParseError>
+where
+    F: Parser<&'a str, O, nom::error::Error<&'a str>>,
+{
+    input
+        .lines()
+        .map(|line| {
+            all_consuming(|i| parser.parse(i))(line)
+                .finish()
+                .map(|(_, output)| output)
+                .map_err(|err| {
+                    let offset = err.input.as_ptr() as usize - input.as_ptr() as usize;
+                    ParseError::new(input, &input[offset..], err.code)
+                })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(parse_unsigned::<u8>("42,"), Ok((",", 42)));
+        assert!(parse_unsigned::<u8>("-4").is_err());
+        assert_eq!(parse_signed::<i64>("-17"), Ok(("", -17)));
+        assert_eq!(parse_signed::<isize>("+3"), Ok(("", 3)));
+    }
+
+    #[test]
+    fn lists_and_blocks() {
+        assert_eq!(
+            parse_all(parse_list(parse_unsigned::<u32>), "1, 2,3 4\n"),
+            Ok(vec![1, 2, 3, 4])
+        );
+        assert_eq!(
+            parse_all(parse_blocks(parse_list(parse_unsigned::<u32>)), "1\n\n2 3"),
+            Ok(vec![vec![1], vec![2, 3]])
+        );
+    }
+
+    #[test]
+    fn coords() {
+        assert_eq!(parse_xy("x=2, y=-18"), Ok(("", Coord::new(2, -18))));
+        assert_eq!(parse_xyz("x=0, y=1, z=-1"), Ok(("", Coord3::new(0, 1, -1))));
+    }
+
+    #[test]
+    fn char_grid() {
+        let digit = |c: char| c.to_digit(10);
+        let (rest, grid) = parse_char_grid(digit)("123\n456\n\nrest").unwrap();
+
+        assert_eq!(rest, "\n\nrest");
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coord::new(1, 1)], 5);
+        assert!(parse_char_grid(digit)("12\n3").is_err());
+    }
+
+    #[test]
+    fn line_error_location() {
+        let err = parse_lines(parse_unsigned::<u32>, "1\n2\n3a\n4").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 2));
+        assert_eq!(err.snippet, "3a");
+    }
+
+    #[test]
+    fn error_location() {
+        let numbers = parse_blocks(parse_list(parse_unsigned::<u32>));
+        let err = parse_all(numbers, "1, 2\n\n3 x").unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.snippet, "3 x");
+        assert_eq!(
+            err.to_string(),
+            "Unexpected trailing input at line 3, column 3:\n  3 x\n    ^"
+        );
+    }
+
+    #[test]
+    fn end_of_input() {
+        let row = |c: char| Some(c).filter(|c| c.is_ascii_digit());
+        let err = parse_all(preceded(char('#'), parse_char_grid(row)), "#").unwrap_err();
 
-    map(map_res(digit1, |s: &str| s.parse::<isize>()), move |num| {
-        num * mul
-    })(input)
+        assert_eq!(err.description(), "End of file");
+        assert_eq!(
+            parse_lines(parse_unsigned::<u32>, "1\n2 3")
+                .unwrap_err()
+                .to_string(),
+            "Unexpected trailing input at line 2, column 2:\n  2 3\n   ^"
+        );
+    }
 }
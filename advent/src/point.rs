@@ -0,0 +1,267 @@
+use std::{
+    array,
+    fmt::Display,
+    iter::Sum,
+    ops::{Add, AddAssign, Deref, DerefMut, Div, Index, IndexMut, Mul, Neg, Sub, SubAssign},
+};
+
+pub type PointT = isize;
+
+/// A point (or a vector) in `N`-dimensional integer space.
+///
+/// For `N` of 2, 3 and 4 the components can also be accessed by name
+/// (`p.x`, `p.y`, `p.z`, `p.w`).
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, dbg_pls::DebugPls)]
+pub struct Point<const N: usize>(pub [PointT; N]);
+
+impl<const N: usize> Point<N> {
+    pub const MIN: Point<N> = Point([PointT::MIN; N]);
+    pub const MAX: Point<N> = Point([PointT::MAX; N]);
+
+    pub const fn zero() -> Point<N> {
+        Point([0; N])
+    }
+
+    pub const fn splat(value: PointT) -> Point<N> {
+        Point([value; N])
+    }
+
+    /// The unit vector along `axis`.
+    pub fn unit(axis: usize) -> Point<N> {
+        Point(array::from_fn(|i| (i == axis) as PointT))
+    }
+
+    fn map(self, f: impl FnMut(PointT) -> PointT) -> Point<N> {
+        Point(self.0.map(f))
+    }
+
+    fn zip_map(self, rhs: Point<N>, mut f: impl FnMut(PointT, PointT) -> PointT) -> Point<N> {
+        Point(array::from_fn(|i| f(self.0[i], rhs.0[i])))
+    }
+
+    pub fn min(self, rhs: Point<N>) -> Point<N> {
+        self.zip_map(rhs, PointT::min)
+    }
+
+    pub fn max(self, rhs: Point<N>) -> Point<N> {
+        self.zip_map(rhs, PointT::max)
+    }
+
+    pub fn clamp(self, min: Point<N>, max: Point<N>) -> Point<N> {
+        self.max(min).min(max)
+    }
+
+    pub fn abs(self) -> Point<N> {
+        self.map(PointT::abs)
+    }
+
+    pub fn signum(self) -> Point<N> {
+        self.map(PointT::signum)
+    }
+
+    pub fn manhattan(&self) -> PointT {
+        self.0.iter().map(|c| c.abs()).sum()
+    }
+
+    pub fn inf_norm(&self) -> PointT {
+        self.0.iter().map(|c| c.abs()).max().unwrap_or(0)
+    }
+
+    /// The `2 * N` steps along a single axis.
+    pub fn orthogonal_steps() -> impl Iterator<Item = Point<N>> {
+        (0..N).flat_map(|axis| [-Point::unit(axis), Point::unit(axis)])
+    }
+
+    /// All `3^N - 1` steps to a touching point, diagonals included.
+    pub fn all_steps() -> impl Iterator<Item = Point<N>> {
+        let count = 3usize.pow(N as u32);
+        (0..count)
+            .map(|mut idx| {
+                Point(array::from_fn(|_| {
+                    let component = (idx % 3) as PointT - 1;
+                    idx /= 3;
+                    component
+                }))
+            })
+            .filter(|step| *step != Point::zero())
+    }
+
+    pub fn orthogonal_neighbours(self) -> impl Iterator<Item = Point<N>> {
+        Self::orthogonal_steps().map(move |step| self + step)
+    }
+
+    pub fn neighbours(self) -> impl Iterator<Item = Point<N>> {
+        Self::all_steps().map(move |step| self + step)
+    }
+}
+
+impl<const N: usize> From<[PointT; N]> for Point<N> {
+    fn from(value: [PointT; N]) -> Self {
+        Point(value)
+    }
+}
+
+impl<const N: usize> Index<usize> for Point<N> {
+    type Output = PointT;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+impl<const N: usize> IndexMut<usize> for Point<N> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.0[index]
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        self.zip_map(rhs, |a, b| a + b)
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self.zip_map(rhs, |a, b| a - b)
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|c| -c)
+    }
+}
+
+impl<const N: usize> Mul<PointT> for Point<N> {
+    type Output = Point<N>;
+
+    fn mul(self, rhs: PointT) -> Self::Output {
+        self.map(|c| c * rhs)
+    }
+}
+
+impl<const N: usize> Mul<Point<N>> for PointT {
+    type Output = Point<N>;
+
+    fn mul(self, rhs: Point<N>) -> Self::Output {
+        rhs.map(|c| self * c)
+    }
+}
+
+impl<const N: usize> Div<PointT> for Point<N> {
+    type Output = Point<N>;
+
+    fn div(self, rhs: PointT) -> Self::Output {
+        self.map(|c| c / rhs)
+    }
+}
+
+impl<const N: usize> Div<Point<N>> for PointT {
+    type Output = Point<N>;
+
+    fn div(self, rhs: Point<N>) -> Self::Output {
+        rhs.map(|c| self / c)
+    }
+}
+
+impl<const N: usize> Sum for Point<N> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Point::zero(), |acc, curr| acc + curr)
+    }
+}
+
+impl<const N: usize> Display for Point<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "(")?;
+        for (idx, component) in self.0.iter().enumerate() {
+            if idx != 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{}", component)?;
+        }
+        write!(f, ")")
+    }
+}
+
+macro_rules! named_components {
+    ( $n:literal, $name:ident { $( $field:ident ),* } ) => {
+        #[repr(C)]
+        #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+        pub struct $name {
+            $( pub $field: PointT ),*
+        }
+
+        impl Deref for Point<$n> {
+            type Target = $name;
+
+            fn deref(&self) -> &Self::Target {
+                // SAFETY: the target is `repr(C)` with exactly `$n` fields of `PointT`, so it
+                // has the same size and alignment as `[PointT; $n]`.
+                unsafe { &*self.0.as_ptr().cast::<$name>() }
+            }
+        }
+
+        impl DerefMut for Point<$n> {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                // SAFETY: see `Deref` above.
+                unsafe { &mut *self.0.as_mut_ptr().cast::<$name>() }
+            }
+        }
+    };
+}
+
+named_components!(2, XY { x, y });
+named_components!(3, XYZ { x, y, z });
+named_components!(4, XYZW { x, y, z, w });
+
+#[cfg(test)]
+mod tests {
+    use super::Point;
+    use crate::{coord::Coord, coord3::Coord3};
+
+    #[test]
+    fn named_components() {
+        let mut coord = Coord3::new(1, 2, 3);
+        coord.y += 5;
+
+        assert_eq!((coord.x, coord.y, coord.z), (1, 7, 3));
+        assert_eq!(coord, Point([1, 7, 3]));
+    }
+
+    #[test]
+    fn neighbour_counts() {
+        assert_eq!(Coord::orthogonal_steps().count(), 4);
+        assert_eq!(Coord::all_steps().count(), 8);
+        assert_eq!(Coord3::orthogonal_steps().count(), 6);
+        assert_eq!(Point::<4>::all_steps().count(), 80);
+    }
+
+    #[test]
+    fn norms() {
+        let point = Point([3, -4, 1, -2]);
+
+        assert_eq!(point.manhattan(), 10);
+        assert_eq!(point.inf_norm(), 4);
+        assert_eq!(point.signum(), Point([1, -1, 1, -1]));
+    }
+}
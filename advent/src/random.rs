@@ -0,0 +1,218 @@
+//! A seeded random number generator, to generate puzzle inputs, and a runner
+//! for property tests on them.
+//!
+//! Properties are checked on [`CASES`] inputs by default, each generated from
+//! its own seed. A failing case reports its seed, and setting `AOC_SEED` to it
+//! runs that case alone:
+//!
+//! ```text
+//! AOC_SEED=1234 cargo test -p day13
+//! ```
+
+use std::{
+    fmt::Debug,
+    ops::RangeInclusive,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::solution::{solve, Solution};
+
+pub const SEED_VAR: &str = "AOC_SEED";
+
+/// The number of cases checked by a property, unless told otherwise.
+pub const CASES: usize = 64;
+
+/// The first seed, so that tests check the same cases on every run.
+const BASE_SEED: u64 = 0x2022_1201;
+
+/// A SplitMix64 generator: tiny, fast, and good enough to shuffle puzzle
+/// inputs. It's not meant for anything else.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A number from 0 to `n` excluded.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Can't pick a number below 0");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// A number within `range`, both ends included.
+    pub fn range<T: Uniform>(&mut self, range: RangeInclusive<T>) -> T {
+        let (low, high) = (range.start().to_i128(), range.end().to_i128());
+        assert!(low <= high, "Can't pick a number in an empty range");
+
+        let span = (high - low + 1) as u128;
+        let offset = if span > u64::MAX as u128 {
+            self.next_u64() as u128
+        } else {
+            self.below(span as u64) as u128
+        };
+        T::from_i128(low + offset as i128)
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..=i));
+        }
+    }
+
+    /// `count` different items out of `items`, in random order.
+    pub fn sample<T: Clone>(&mut self, items: &[T], count: usize) -> Vec<T> {
+        let mut items = items.to_vec();
+        self.shuffle(&mut items);
+        items.truncate(count);
+        items
+    }
+}
+
+/// The integers [`Rng::range`] picks from.
+pub trait Uniform: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(n: i128) -> Self;
+}
+
+macro_rules! uniform {
+    ($($t:ty),*) => {
+        $(
+            impl Uniform for $t {
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                fn from_i128(n: i128) -> Self {
+                    n as $t
+                }
+            }
+        )*
+    };
+}
+
+uniform!(u8, u32, u64, usize, i32, i64, isize);
+
+/// The seeds of the cases to check: the one in `AOC_SEED` if it's set, or
+/// `cases` seeds otherwise.
+fn seeds(cases: usize) -> Vec<u64> {
+    match std::env::var(SEED_VAR) {
+        Ok(seed) => vec![seed
+            .trim()
+            .parse()
+            .unwrap_or_else(|_| panic!("{} should be a number, got {}", SEED_VAR, seed))],
+        Err(_) => (0..cases as u64).map(|case| BASE_SEED + case).collect(),
+    }
+}
+
+/// Checks `property` on `cases` random generators. It fails by panicking,
+/// and the seed it failed with is reported.
+pub fn check(cases: usize, mut property: impl FnMut(&mut Rng)) {
+    for seed in seeds(cases) {
+        let res = panic::catch_unwind(AssertUnwindSafe(|| property(&mut Rng::new(seed))));
+        if let Err(err) = res {
+            eprintln!("The property failed with {}={}", SEED_VAR, seed);
+            panic::resume_unwind(err);
+        }
+    }
+}
+
+/// Checks that the inputs made by `generate` parse, and that every part
+/// solves them without an error.
+pub fn check_solves<S: Solution>(cases: usize, mut generate: impl FnMut(&mut Rng) -> String) {
+    check(cases, |rng| {
+        let input = generate(rng);
+        if let Err(err) = solve::<S>(&input, None) {
+            panic!("{} for the input:\n{}", err, input);
+        }
+    });
+}
+
+/// Checks that `parse` reads back what `show` printed, for the values made
+/// by `generate`.
+pub fn check_round_trip<T, E>(
+    cases: usize,
+    mut generate: impl FnMut(&mut Rng) -> T,
+    show: impl Fn(&T) -> String,
+    parse: impl Fn(&str) -> Result<T, E>,
+) where
+    T: Debug + PartialEq,
+    E: Debug,
+{
+    check(cases, |rng| {
+        let value = generate(rng);
+        let shown = show(&value);
+        match parse(&shown) {
+            Ok(parsed) => assert_eq!(parsed, value, "{} was read back wrong", shown),
+            Err(err) => panic!("Can't read back {}: {:?}", shown, err),
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check, Rng};
+
+    #[test]
+    fn seeded() {
+        let numbers = |seed| {
+            let mut rng = Rng::new(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(numbers(1), numbers(1));
+        assert_ne!(numbers(1), numbers(2));
+    }
+
+    #[test]
+    fn ranges() {
+        check(16, |rng| {
+            for _ in 0..100 {
+                assert!((-3..=3).contains(&rng.range(-3..=3)));
+                assert_eq!(rng.range(7usize..=7), 7);
+                assert!(rng.below(10) < 10);
+            }
+
+            let full = rng.range(i64::MIN..=i64::MAX);
+            assert!((i64::MIN..=i64::MAX).contains(&full));
+            assert!(!rng.chance(0.));
+            assert!(rng.chance(1.));
+        });
+    }
+
+    #[test]
+    fn shuffle_and_sample() {
+        check(16, |rng| {
+            let mut items: Vec<_> = (0..20).collect();
+            rng.shuffle(&mut items);
+            let mut sorted = items.clone();
+            sorted.sort();
+            assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+
+            let mut sample = rng.sample(&items, 5);
+            sample.sort();
+            sample.dedup();
+            assert_eq!(sample.len(), 5);
+        });
+    }
+}
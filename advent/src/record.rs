@@ -0,0 +1,248 @@
+//! Recording of a simulation's frames, saved as an [asciicast v2] file that
+//! can be replayed with `asciinema play`.
+//!
+//! Simulations push their state with [`frame`] as they go. It does nothing,
+//! and doesn't even render the frame, unless a recording was started with
+//! `--record <path>`.
+//!
+//! [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+
+use std::{
+    cell::RefCell,
+    fmt::Display,
+    fs::File,
+    io::{self, BufWriter, Write},
+};
+
+use crate::{report::json_string, usage, Error, Result};
+
+thread_local! {
+    static RECORDER: RefCell<Option<Recorder>> = const { RefCell::new(None) };
+}
+
+/// Adds a frame to the current recording, if any. `render` is only called
+/// for the frames that are kept.
+pub fn frame<D: Display>(render: impl FnOnce() -> D) {
+    RECORDER.with_borrow_mut(|recorder| {
+        if let Some(recorder) = recorder {
+            recorder.push(render);
+        }
+    });
+}
+
+pub fn is_recording() -> bool {
+    RECORDER.with_borrow(Option::is_some)
+}
+
+/// The frames kept so far, every `stride`th one up to `max_frames`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recorder {
+    stride: usize,
+    max_frames: Option<usize>,
+    frame_ms: u64,
+    /// The number of frames pushed, kept or not.
+    pushed: usize,
+    frames: Vec<String>,
+}
+
+impl Recorder {
+    pub fn new(stride: usize, max_frames: Option<usize>, frame_ms: u64) -> Recorder {
+        assert!(stride > 0, "The stride must be positive");
+        Recorder {
+            stride,
+            max_frames,
+            frame_ms,
+            pushed: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn push<D: Display>(&mut self, render: impl FnOnce() -> D) {
+        let is_full = self.max_frames.is_some_and(|max| self.frames.len() >= max);
+        if self.pushed.is_multiple_of(self.stride) && !is_full {
+            self.frames.push(render().to_string());
+        }
+
+        self.pushed += 1;
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Writes the header, sized to fit the largest frame, then each frame
+    /// `frame_ms` after the previous one, drawn on a cleared screen.
+    pub fn write_cast(&self, out: &mut impl Write) -> io::Result<()> {
+        let width = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.lines())
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = self.frames.iter().map(|f| f.lines().count()).max();
+
+        writeln!(
+            out,
+            r#"{{"version": 2, "width": {}, "height": {}}}"#,
+            width.max(1),
+            height.unwrap_or(0).max(1)
+        )?;
+
+        for (idx, frame) in self.frames.iter().enumerate() {
+            let time = (idx as u64 * self.frame_ms) as f64 / 1000.;
+            let data = format!("\x1b[H\x1b[2J{}", frame.replace('\n', "\r\n"));
+            writeln!(out, r#"[{:.3}, "o", {}]"#, time, json_string(&data))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The recording options given on the command line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordOptions {
+    /// Where to save the cast. Nothing is recorded without it.
+    pub path: Option<String>,
+    pub stride: usize,
+    pub max_frames: Option<usize>,
+    pub frame_ms: u64,
+}
+
+impl Default for RecordOptions {
+    fn default() -> Self {
+        RecordOptions {
+            path: None,
+            stride: 1,
+            max_frames: None,
+            frame_ms: 100,
+        }
+    }
+}
+
+impl RecordOptions {
+    pub const USAGE: &'static str = concat!(
+        "  --record <path>     Save the simulation's frames as an asciicast file\n",
+        "  --stride <n>        Only keep every nth frame\n",
+        "  --max-frames <n>    Stop recording after n frames\n",
+        "  --frame-ms <ms>     The time between frames on replay, 100ms by default",
+    );
+
+    /// Reads `arg` if it's a recording option, taking its value from `rest`.
+    /// Returns `None` for any other argument.
+    pub fn parse_option(
+        &mut self,
+        arg: &str,
+        rest: &mut impl Iterator<Item = String>,
+    ) -> Option<Result<()>> {
+        let mut value = || {
+            rest.next()
+                .ok_or_else(|| usage!("Missing the value after {}", arg))
+        };
+        let number = |value: String| {
+            value
+                .parse::<usize>()
+                .ok()
+                .filter(|&n| n > 0)
+                .ok_or_else(|| usage!("{} needs a positive number, got {}", arg, value))
+        };
+
+        let res = match arg {
+            "--record" => value().map(|path| self.path = Some(path)),
+            "--stride" => value().and_then(number).map(|n| self.stride = n),
+            "--max-frames" => value().and_then(number).map(|n| self.max_frames = Some(n)),
+            "--frame-ms" => value().and_then(number).map(|n| self.frame_ms = n as u64),
+            _ => return None,
+        };
+
+        Some(res)
+    }
+
+    /// Runs `f`, recording the frames it pushes if a path was given, and
+    /// saves them once it's done.
+    pub fn record<T>(&self, f: impl FnOnce() -> Result<T>) -> Result<T> {
+        let Some(path) = &self.path else {
+            return f();
+        };
+
+        let recorder = Recorder::new(self.stride, self.max_frames, self.frame_ms);
+        RECORDER.with_borrow_mut(|current| *current = Some(recorder));
+        let res = f();
+        let recorder = RECORDER
+            .with_borrow_mut(Option::take)
+            .expect("The recorder is only taken here");
+
+        let io_error = |err: io::Error| Error::Io(format!("{}: {}", path, err));
+        let mut out = BufWriter::new(File::create(path).map_err(io_error)?);
+        recorder
+            .write_cast(&mut out)
+            .and_then(|()| out.flush())
+            .map_err(io_error)?;
+
+        res
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{frame, is_recording, RecordOptions, Recorder};
+
+    #[test]
+    fn stride_and_max_frames() {
+        let mut recorder = Recorder::new(2, Some(3), 100);
+        for i in 0..10 {
+            recorder.push(|| i);
+        }
+
+        assert_eq!(recorder.frames(), ["0", "2", "4"]);
+    }
+
+    #[test]
+    fn cast() {
+        let mut recorder = Recorder::new(1, None, 250);
+        recorder.push(|| "#.\n.\"#");
+        recorder.push(|| "█\x07");
+
+        let mut cast = Vec::new();
+        recorder.write_cast(&mut cast).unwrap();
+
+        assert_eq!(
+            String::from_utf8(cast).unwrap(),
+            concat!(
+                "{\"version\": 2, \"width\": 3, \"height\": 2}\n",
+                "[0.000, \"o\", \"\\u001b[H\\u001b[2J#.\\r\\n.\\\"#\"]\n",
+                "[0.250, \"o\", \"\\u001b[H\\u001b[2J█\\u0007\"]\n",
+            )
+        );
+    }
+
+    #[test]
+    fn options() {
+        let mut options = RecordOptions::default();
+        let mut args = ["out.cast", "3", "0"].map(String::from).into_iter();
+
+        assert_eq!(options.parse_option("--record", &mut args), Some(Ok(())));
+        assert_eq!(options.parse_option("--stride", &mut args), Some(Ok(())));
+        assert!(options
+            .parse_option("--max-frames", &mut args)
+            .unwrap()
+            .is_err());
+        assert!(options
+            .parse_option("--frame-ms", &mut args)
+            .unwrap()
+            .is_err());
+        assert_eq!(options.parse_option("--sample", &mut args), None);
+
+        assert_eq!(options.path.as_deref(), Some("out.cast"));
+        assert_eq!(options.stride, 3);
+    }
+
+    #[test]
+    fn not_recording() {
+        assert!(!is_recording());
+        frame(|| -> &str { panic!("Frames aren't rendered without a recording") });
+
+        let res = RecordOptions::default().record(|| Ok(is_recording()));
+        assert_eq!(res, Ok(false));
+    }
+}
@@ -0,0 +1,251 @@
+//! Answers in a shape scripts can read without scraping: with
+//! `--format json`, each day is printed as one JSON object on its own line.
+//!
+//! ```text
+//! {"day":10,"answers":{"part1":13140,"part2":["##..##..", ...]},
+//!  "elapsed_ns":812345,"input_hash":"fnv1a64:9f3c...","params":{}}
+//! ```
+//!
+//! Numbers are JSON numbers, multi-line pictures arrays of rows, and every
+//! other answer a string. A day that failed has an `error` instead of its
+//! answers. When the allocations were counted, a `memory` object has the
+//! `allocations`, `bytes` and `peak_bytes` of each stage.
+
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    memory::Memory,
+    params::{self, Used},
+    solution::{Answers, Value},
+    usage, Result,
+};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+}
+
+impl Format {
+    pub const USAGE: &'static str =
+        "  --format <f>        text, the default, or json with timing and input details";
+
+    /// Reads `arg` if it's the format option, taking its value from `rest`.
+    /// Returns `None` for any other argument.
+    pub fn parse_option(
+        arg: &str,
+        rest: &mut impl Iterator<Item = String>,
+    ) -> Option<Result<Format>> {
+        if arg != "--format" {
+            return None;
+        }
+
+        Some(match rest.next().as_deref() {
+            Some("text") => Ok(Format::Text),
+            Some("json") => Ok(Format::Json),
+            Some(format) => Err(usage!("Unknown format {}, expected text or json", format)),
+            None => Err(usage!("Missing the format after --format")),
+        })
+    }
+}
+
+/// The 64-bit FNV-1a hash of the input, to tell which input was solved
+/// without including it.
+pub fn input_hash(input: &str) -> String {
+    let hash = input.bytes().fold(0xcbf2_9ce4_8422_2325u64, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    });
+
+    format!("fnv1a64:{:016x}", hash)
+}
+
+/// A day's answers, or why it has none, with what they were solved from.
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct Report {
+    pub day: Option<usize>,
+    pub answers: Result<Answers>,
+    pub elapsed: Duration,
+    pub input_hash: String,
+    pub params: Used,
+    /// What each stage allocated, if it was counted.
+    pub memory: Memory,
+}
+
+impl Report {
+    /// Solves `input` with `solve`, timing it and keeping track of the
+    /// parameters it used.
+    pub fn measure(input: &str, solve: impl FnOnce() -> Result<Answers>) -> Report {
+        let start = Instant::now();
+        let (answers, params) = params::track(solve);
+
+        Report {
+            day: None,
+            answers,
+            elapsed: start.elapsed(),
+            input_hash: input_hash(input),
+            params,
+            memory: Memory::new(),
+        }
+    }
+
+    pub fn json(&self) -> String {
+        let mut json = String::from("{");
+        if let Some(day) = self.day {
+            _ = write!(json, r#""day":{},"#, day);
+        }
+
+        match &self.answers {
+            Ok(answers) => {
+                let answers: Vec<String> = answers
+                    .iter()
+                    .map(|(part, value)| format!(r#""part{}":{}"#, part, json_value(value)))
+                    .collect();
+                _ = write!(json, r#""answers":{{{}}}"#, answers.join(","));
+            }
+            Err(err) => _ = write!(json, r#""error":{}"#, json_string(&err.to_string())),
+        }
+
+        let params: Vec<String> = self
+            .params
+            .iter()
+            .map(|(name, value)| {
+                let value = value.as_deref().map_or("null".to_string(), json_string);
+                format!("{}:{}", json_string(name), value)
+            })
+            .collect();
+
+        _ = write!(
+            json,
+            r#","elapsed_ns":{},"input_hash":{},"params":{{{}}}"#,
+            self.elapsed.as_nanos(),
+            json_string(&self.input_hash),
+            params.join(",")
+        );
+
+        if !self.memory.is_empty() {
+            let stages: Vec<String> = self
+                .memory
+                .iter()
+                .map(|(stage, usage)| {
+                    format!(
+                        r#""{}":{{"allocations":{},"bytes":{},"peak_bytes":{}}}"#,
+                        stage, usage.allocations, usage.bytes, usage.peak_bytes
+                    )
+                })
+                .collect();
+            _ = write!(json, r#","memory":{{{}}}"#, stages.join(","));
+        }
+
+        json.push('}');
+        json
+    }
+}
+
+fn json_value(value: &Value) -> String {
+    match value {
+        Value::Number(n) => n.to_string(),
+        Value::Text(text) => json_string(text),
+        Value::Grid(rows) => {
+            let rows: Vec<String> = rows.iter().map(|row| json_string(row)).collect();
+            format!("[{}]", rows.join(","))
+        }
+    }
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut json = String::with_capacity(s.len() + 2);
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => _ = write!(json, "\\u{:04x}", c as u32),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{input_hash, Format, Report};
+    use crate::{memory::Usage, solution::Value, Error};
+
+    #[test]
+    fn format_option() {
+        let parse = |args: &str| {
+            let mut args = args.split_whitespace().map(String::from);
+            let arg = args.next().unwrap();
+            Format::parse_option(&arg, &mut args)
+        };
+
+        assert_eq!(parse("--format json"), Some(Ok(Format::Json)));
+        assert_eq!(parse("--format text"), Some(Ok(Format::Text)));
+        assert!(matches!(parse("--format yaml"), Some(Err(_))));
+        assert!(matches!(parse("--format"), Some(Err(_))));
+        assert_eq!(parse("--sample"), None);
+    }
+
+    #[test]
+    fn hashes() {
+        assert_eq!(input_hash(""), "fnv1a64:cbf29ce484222325");
+        assert_eq!(input_hash("a"), "fnv1a64:af63dc4c8601ec8c");
+        assert_ne!(input_hash("1\n2\n"), input_hash("2\n1\n"));
+    }
+
+    #[test]
+    fn json() {
+        let report = Report {
+            day: Some(10),
+            answers: Ok(vec![
+                (1, Value::Number(-13140)),
+                (2, Value::Grid(vec!["#.".to_string(), ".\"".to_string()])),
+            ]),
+            elapsed: Duration::from_nanos(1500),
+            input_hash: input_hash("a"),
+            params: vec![("rounds", Some("20".to_string())), ("key", None)],
+            memory: vec![],
+        };
+
+        assert_eq!(
+            report.json(),
+            concat!(
+                r##"{"day":10,"answers":{"part1":-13140,"part2":["#.",".\""]},"##,
+                r#""elapsed_ns":1500,"input_hash":"fnv1a64:af63dc4c8601ec8c","#,
+                r#""params":{"rounds":"20","key":null}}"#
+            )
+        );
+
+        let failed = Report {
+            day: None,
+            answers: Err(Error::NoSolution),
+            elapsed: Duration::ZERO,
+            input_hash: input_hash(""),
+            params: vec![],
+            memory: vec![(
+                "parse",
+                Usage {
+                    allocations: 3,
+                    bytes: 120,
+                    peak_bytes: 80,
+                },
+            )],
+        };
+        assert!(failed.json().starts_with(r#"{"error":""#));
+        assert!(failed.json().ends_with(concat!(
+            r#""params":{},"#,
+            r#""memory":{"parse":{"allocations":3,"bytes":120,"peak_bytes":80}}}"#
+        )));
+    }
+}
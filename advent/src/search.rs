@@ -0,0 +1,338 @@
+//! Breadth-first, Dijkstra and A* searches over anything implementing
+//! [`Graph`].
+//!
+//! Every search takes any number of start nodes, which are all at cost 0,
+//! and a goal predicate. The search stops as soon as a goal node is
+//! expanded; pass `|_| false` to explore everything reachable instead.
+//! The returned [`Search`] keeps the cost and predecessor of every node it
+//! reached, so paths can be reconstructed to the goal or to any other node.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+pub trait Graph {
+    type Node: Clone + Eq + Hash;
+
+    fn neighbours(&self, node: &Self::Node) -> impl Iterator<Item = Self::Node>;
+
+    /// The cost of stepping from `from` to its neighbour `to`. Ignored by
+    /// [`bfs`].
+    fn cost(&self, _from: &Self::Node, _to: &Self::Node) -> usize {
+        1
+    }
+
+    /// A lower bound on the cost from `node` to the closest goal, only used
+    /// by [`astar`]. Must never overestimate.
+    fn heuristic(&self, _node: &Self::Node) -> usize {
+        0
+    }
+}
+
+/// A graph with unit costs given by a closure returning the neighbours of
+/// a node.
+pub struct FnGraph<N, F> {
+    neighbours: F,
+    _node: PhantomData<fn(&N)>,
+}
+
+pub fn from_fn<N, F, I>(neighbours: F) -> FnGraph<N, F>
+where
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    FnGraph {
+        neighbours,
+        _node: PhantomData,
+    }
+}
+
+impl<N, F, I> Graph for FnGraph<N, F>
+where
+    N: Clone + Eq + Hash,
+    F: Fn(&N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    type Node = N;
+
+    fn neighbours(&self, node: &N) -> impl Iterator<Item = N> {
+        (self.neighbours)(node).into_iter()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Visit<N> {
+    cost: usize,
+    parent: Option<N>,
+}
+
+/// The outcome of a search.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    visits: HashMap<N, Visit<N>>,
+    goal: Option<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Search<N> {
+        Search {
+            visits: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    /// The first goal node the search expanded, if any.
+    pub fn goal(&self) -> Option<&N> {
+        self.goal.as_ref()
+    }
+
+    pub fn cost(&self) -> Option<usize> {
+        self.cost_to(self.goal.as_ref()?)
+    }
+
+    pub fn path(&self) -> Option<Vec<N>> {
+        self.path_to(self.goal.as_ref()?)
+    }
+
+    /// The cost of the best known path to `node`. This is only guaranteed to
+    /// be optimal for nodes that were expanded, which is every reached node
+    /// unless the search stopped at a goal.
+    pub fn cost_to(&self, node: &N) -> Option<usize> {
+        self.visits.get(node).map(|visit| visit.cost)
+    }
+
+    /// The node `node` was reached from, `None` for start nodes and nodes
+    /// that were never reached.
+    pub fn parent(&self, node: &N) -> Option<&N> {
+        self.visits.get(node)?.parent.as_ref()
+    }
+
+    /// The path from one of the start nodes to `node`, both included.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        let mut path = vec![node.clone()];
+        let mut visit = self.visits.get(node)?;
+        while let Some(parent) = &visit.parent {
+            path.push(parent.clone());
+            visit = &self.visits[parent];
+        }
+
+        path.reverse();
+        Some(path)
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.visits.contains_key(node)
+    }
+
+    /// All nodes reached by the search, in no particular order.
+    pub fn reached(&self) -> impl Iterator<Item = &N> {
+        self.visits.keys()
+    }
+
+    pub fn len(&self) -> usize {
+        self.visits.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.visits.is_empty()
+    }
+}
+
+pub fn bfs<G, S, P>(graph: &G, starts: S, mut is_goal: P) -> Search<G::Node>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    P: FnMut(&G::Node) -> bool,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = search.visits.entry(start.clone()) {
+            entry.insert(Visit {
+                cost: 0,
+                parent: None,
+            });
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, cost)) = queue.pop_front() {
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for neighbour in graph.neighbours(&node) {
+            if let Entry::Vacant(entry) = search.visits.entry(neighbour.clone()) {
+                entry.insert(Visit {
+                    cost: cost + 1,
+                    parent: Some(node.clone()),
+                });
+                queue.push_back((neighbour, cost + 1));
+            }
+        }
+    }
+
+    search
+}
+
+pub fn dijkstra<G, S, P>(graph: &G, starts: S, is_goal: P) -> Search<G::Node>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    P: FnMut(&G::Node) -> bool,
+{
+    best_first(graph, starts, is_goal, |_| 0)
+}
+
+pub fn astar<G, S, P>(graph: &G, starts: S, is_goal: P) -> Search<G::Node>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    P: FnMut(&G::Node) -> bool,
+{
+    best_first(graph, starts, is_goal, |node| graph.heuristic(node))
+}
+
+fn best_first<G, S, P, H>(graph: &G, starts: S, mut is_goal: P, heuristic: H) -> Search<G::Node>
+where
+    G: Graph,
+    S: IntoIterator<Item = G::Node>,
+    P: FnMut(&G::Node) -> bool,
+    H: Fn(&G::Node) -> usize,
+{
+    let mut search = Search::new();
+    // Nodes can't be compared, so the heap holds indices into `nodes`.
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = search.visits.entry(start.clone()) {
+            entry.insert(Visit {
+                cost: 0,
+                parent: None,
+            });
+            heap.push(Reverse((heuristic(&start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, idx))) = heap.pop() {
+        let node = nodes[idx].clone();
+        if search.visits[&node].cost < cost {
+            continue;
+        }
+
+        if is_goal(&node) {
+            search.goal = Some(node);
+            break;
+        }
+
+        for neighbour in graph.neighbours(&node) {
+            let new_cost = cost + graph.cost(&node, &neighbour);
+            let visit = Visit {
+                cost: new_cost,
+                parent: Some(node.clone()),
+            };
+
+            match search.visits.entry(neighbour.clone()) {
+                Entry::Occupied(mut entry) if entry.get().cost > new_cost => {
+                    entry.insert(visit);
+                }
+                Entry::Occupied(_) => continue,
+                Entry::Vacant(entry) => _ = entry.insert(visit),
+            }
+
+            heap.push(Reverse((
+                new_cost + heuristic(&neighbour),
+                new_cost,
+                nodes.len(),
+            )));
+            nodes.push(neighbour);
+        }
+    }
+
+    search
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{astar, bfs, dijkstra, from_fn, Graph};
+    use crate::{coord::Coord, grid::Grid};
+
+    const MAZE: &str = "\
+        S.#....\n\
+        .##.##.\n\
+        ...#..E\n\
+        .#...#.\n";
+
+    fn maze() -> Grid<char> {
+        Grid::from_chars(MAZE, |c| c)
+    }
+
+    struct Weighted(Grid<char>);
+
+    impl Graph for Weighted {
+        type Node = Coord;
+
+        fn neighbours(&self, node: &Coord) -> impl Iterator<Item = Coord> {
+            self.0.neighbours(*node).filter(|&n| self.0[n] != '#')
+        }
+
+        fn cost(&self, _from: &Coord, to: &Coord) -> usize {
+            // Walking on row 2 is slow.
+            if to.y == 2 {
+                5
+            } else {
+                1
+            }
+        }
+
+        fn heuristic(&self, node: &Coord) -> usize {
+            (Coord::new(6, 2) - *node).manhattan() as usize
+        }
+    }
+
+    #[test]
+    fn bfs_path() {
+        let maze = maze();
+        let graph = from_fn(|&c| maze.neighbours(c).filter(|&n| maze[n] != '#'));
+        let search = bfs(&graph, [Coord::zero()], |&c| maze[c] == 'E');
+
+        assert_eq!(search.goal(), Some(&Coord::new(6, 2)));
+        assert_eq!(search.cost(), Some(10));
+
+        let path = search.path().unwrap();
+        assert_eq!(path.len(), 11);
+        assert_eq!(path.first(), Some(&Coord::zero()));
+        assert!(path.windows(2).all(|w| (w[1] - w[0]).manhattan() == 1));
+    }
+
+    #[test]
+    fn multi_source() {
+        let maze = maze();
+        let graph = from_fn(|&c| maze.neighbours(c).filter(|&n| maze[n] != '#'));
+        let search = bfs(&graph, [Coord::zero(), Coord::new(6, 0)], |_| false);
+
+        assert_eq!(search.cost_to(&Coord::new(6, 2)), Some(2));
+        assert_eq!(search.path_to(&Coord::new(0, 3)).unwrap()[0], Coord::zero());
+        assert!(!search.contains(&Coord::new(2, 0)));
+    }
+
+    #[test]
+    fn weighted() {
+        let graph = Weighted(maze());
+        let end = |&c: &Coord| c == Coord::new(6, 2);
+
+        let dijkstra = dijkstra(&graph, [Coord::zero()], end);
+        let astar = astar(&graph, [Coord::zero()], end);
+
+        assert_eq!(dijkstra.cost(), Some(34));
+        assert_eq!(astar.cost(), Some(34));
+        assert!(astar.len() <= dijkstra.len());
+    }
+}
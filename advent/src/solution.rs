@@ -0,0 +1,319 @@
+//! The shape shared by every day, so that they can all be run the same way.
+
+use std::{
+    env,
+    fmt::{self, Display},
+};
+
+use crate::{
+    image::ImageOptions,
+    input::{Bundled, Source},
+    log,
+    memory::{self, Memory},
+    params::Overrides,
+    record::RecordOptions,
+    report::{Format, Report},
+    step::Simulation,
+    usage, Result,
+};
+
+/// An answer, as printed and as a typed value in reports.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    Number(i128),
+    Text(String),
+    /// A picture, like the letters drawn by a CRT, one row per string.
+    Grid(Vec<String>),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Text(text) => f.write_str(text),
+            Value::Grid(rows) => f.write_str(&rows.join("\n")),
+        }
+    }
+}
+
+/// The type of a part's answer.
+pub trait Answer: Display {
+    fn value(&self) -> Value;
+}
+
+macro_rules! number_answers {
+    ( $( $ty:ty ),* ) => {
+        $(
+            impl Answer for $ty {
+                fn value(&self) -> Value {
+                    Value::Number(*self as i128)
+                }
+            }
+        )*
+    };
+}
+
+number_answers!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Text spanning several lines is a picture.
+impl Answer for String {
+    fn value(&self) -> Value {
+        match self.contains('\n') {
+            true => Value::Grid(self.lines().map(String::from).collect()),
+            false => Value::Text(self.clone()),
+        }
+    }
+}
+
+pub trait Solution {
+    /// The parsed puzzle input, shared by both parts.
+    type Input;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    /// The number of parts. Day 25 only has one.
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parses the input along with the day's parameters. Days with
+    /// parameters override this, the others don't accept any.
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        overrides.ensure_no_args()?;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Self::Answer1>;
+    fn part2(input: &Self::Input) -> Result<Self::Answer2>;
+
+    /// The simulation solving `part`, to step through its states. Only the
+    /// days that simulate something have one.
+    fn simulate(_input: &Self::Input, _part: u8) -> Option<Simulation> {
+        None
+    }
+}
+
+/// Each part number with its answer.
+pub type Answers = Vec<(u8, Value)>;
+
+/// Parses `input` once and solves the given part, or every part if `part`
+/// is `None`.
+pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Result<Answers> {
+    solve_with::<S>(input, part, &Overrides::default())
+}
+
+/// The parts to solve, every one if `part` is `None`.
+fn parts<S: Solution>(part: Option<u8>) -> Result<Vec<u8>> {
+    match part {
+        None => Ok((1..=S::PARTS).collect()),
+        Some(part) if (1..=S::PARTS).contains(&part) => Ok(vec![part]),
+        Some(part) => Err(usage!("There is no part {}", part)),
+    }
+}
+
+fn solve_part<S: Solution>(input: &S::Input, part: u8) -> Result<Value> {
+    Ok(match part {
+        1 => S::part1(input)?.value(),
+        _ => S::part2(input)?.value(),
+    })
+}
+
+/// Same as [`solve`], with some of the day's parameters overridden.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    part: Option<u8>,
+    overrides: &Overrides,
+) -> Result<Answers> {
+    let parts = parts::<S>(part)?;
+
+    let input = S::parse_with(input, overrides)?;
+    parts
+        .into_iter()
+        .map(|part| Ok((part, solve_part::<S>(&input, part)?)))
+        .collect()
+}
+
+/// Same as [`solve_with`], also counting what the parsing and each part
+/// allocate.
+pub fn solve_counting<S: Solution>(
+    input: &str,
+    part: Option<u8>,
+    overrides: &Overrides,
+) -> Result<(Answers, Memory)> {
+    let parts = parts::<S>(part)?;
+
+    let (input, usage) = memory::measure(|| S::parse_with(input, overrides));
+    let input = input?;
+    let mut memory = vec![("parse", usage)];
+
+    let mut answers = Vec::new();
+    for part in parts {
+        let (answer, usage) = memory::measure(|| solve_part::<S>(&input, part));
+        answers.push((part, answer?));
+        memory.push((["part1", "part2"][part as usize - 1], usage));
+    }
+
+    Ok((answers, memory))
+}
+
+/// Parses `input` and builds the simulation of `part`, failing for the days
+/// without one.
+pub fn simulate_with<S: Solution>(
+    input: &str,
+    part: u8,
+    overrides: &Overrides,
+) -> Result<Simulation> {
+    parts::<S>(Some(part))?;
+
+    let input = S::parse_with(input, overrides)?;
+    S::simulate(&input, part)
+        .ok_or_else(|| usage!("Part {} has no simulation to step through", part))
+}
+
+/// Prints answers as returned by [`solve`]. Answers spanning several lines,
+/// like pictures, start on their own line.
+pub fn print_answers(answers: &[(u8, Value)]) {
+    for (part, answer) in answers {
+        match answer {
+            Value::Grid(_) => println!("Part {}:\n{}", part, answer),
+            _ => println!("Part {}: {}", part, answer),
+        }
+    }
+}
+
+/// The command line options of a day's binary.
+#[derive(Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Options {
+    pub source: Source,
+    pub overrides: Overrides,
+    pub record: RecordOptions,
+    pub images: ImageOptions,
+    pub format: Format,
+    /// The number of `-v`s.
+    pub verbosity: u8,
+}
+
+impl Options {
+    pub fn from_args(args: impl IntoIterator<Item = String>) -> Result<Options> {
+        let mut args = args.into_iter();
+        let mut options = Options::default();
+
+        while let Some(arg) = args.next() {
+            if log::parse_verbosity(&arg, &mut options.verbosity) {
+                continue;
+            }
+
+            if let Some(source) = Source::parse_option(&arg, &mut args) {
+                options.source = source?;
+            } else if let Some(res) = options.overrides.parse_option(&arg, &mut args) {
+                res?;
+            } else if let Some(res) = options.record.parse_option(&arg, &mut args) {
+                res?;
+            } else if let Some(res) = options.images.parse_option(&arg, &mut args) {
+                res?;
+            } else if let Some(format) = Format::parse_option(&arg, &mut args) {
+                options.format = format?;
+            } else {
+                return Err(usage!("Unknown argument {}", arg));
+            }
+        }
+
+        Ok(options)
+    }
+}
+
+/// Solves every part of the input chosen on the command line, and prints
+/// the answers, or a report of them with `--format json`.
+pub fn run<S: Solution>(bundled: Bundled) -> Result<()> {
+    let options = Options::from_args(env::args().skip(1)).inspect_err(|_| {
+        eprintln!(
+            "Options:\n{}\n{}\n{}\n{}\n{}\n{}",
+            Source::USAGE,
+            Overrides::USAGE,
+            RecordOptions::USAGE,
+            ImageOptions::USAGE,
+            Format::USAGE,
+            log::USAGE
+        );
+    })?;
+    log::init(options.verbosity)?;
+
+    let input = options.source.read(bundled)?;
+    let solve = || {
+        options.record.record(|| {
+            options
+                .images
+                .export(|| solve_with::<S>(&input, None, &options.overrides))
+        })
+    };
+
+    match options.format {
+        Format::Text => print_answers(&solve()?),
+        Format::Json => {
+            let report = Report::measure(&input, solve);
+            println!("{}", report.json());
+            report.answers?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Answer, Options, Value};
+    use crate::{input::Source, report::Format};
+
+    fn parse(args: &str) -> crate::Result<Options> {
+        Options::from_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn from_args() {
+        let source = |args| parse(args).map(|options| options.source);
+
+        assert_eq!(source(""), Ok(Source::Bundled));
+        assert_eq!(source("--sample"), Ok(Source::Sample));
+        assert_eq!(source("--input -"), Ok(Source::Stdin));
+        assert_eq!(source("-"), Ok(Source::Stdin));
+        assert_eq!(
+            source("--input in.txt --param rounds=3"),
+            Ok(Source::File("in.txt".to_string()))
+        );
+
+        assert!(parse("--param rounds=3").unwrap().overrides.has_args());
+        assert_eq!(
+            parse("--record out.cast --stride 2")
+                .unwrap()
+                .record
+                .path
+                .as_deref(),
+            Some("out.cast")
+        );
+        assert!(parse("--input").is_err());
+        assert!(parse("--param").is_err());
+        assert!(parse("--param rounds").is_err());
+        assert!(parse("--frob").is_err());
+        assert_eq!(parse("-v --sample -vv").map(|o| o.verbosity), Ok(3));
+        assert_eq!(parse("--format json").map(|o| o.format), Ok(Format::Json));
+        assert!(parse("--format").is_err());
+    }
+
+    #[test]
+    fn answer_values() {
+        assert_eq!(42usize.value(), Value::Number(42));
+        assert_eq!((-7i64).value(), Value::Number(-7));
+        assert_eq!(
+            "2=-1=0".to_string().value(),
+            Value::Text("2=-1=0".to_string())
+        );
+
+        let picture = "#.#\n.#.".to_string();
+        assert_eq!(
+            picture.value(),
+            Value::Grid(vec!["#.#".to_string(), ".#.".to_string()])
+        );
+        assert_eq!(picture.value().to_string(), picture);
+    }
+}
@@ -0,0 +1,279 @@
+use std::{
+    cell::Cell,
+    collections::{hash_map, HashMap},
+    fmt::Display,
+};
+
+use crate::{
+    bounds::{Bounds, CoordBounded},
+    coord::Coord,
+};
+
+/// An unbounded grid that only stores occupied cells.
+///
+/// The bounding box of the occupied cells is kept up to date on insertion.
+/// Removing a cell on the edge of the box only marks it as stale, and it is
+/// recalculated the next time it's asked for.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    bounds: Cell<Bounds>,
+    bounds_stale: Cell<bool>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: Cell::new(Bounds::new()),
+            bounds_stale: Cell::new(false),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.cells.contains_key(&coord)
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.cells.get(&coord)
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&coord)
+    }
+
+    pub fn insert(&mut self, coord: Coord, value: T) -> Option<T> {
+        if !self.bounds_stale.get() {
+            self.bounds.set(self.bounds.get().with(&coord));
+        }
+
+        self.cells.insert(coord, value)
+    }
+
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        let removed = self.cells.remove(&coord)?;
+
+        let bounds = self.bounds.get();
+        if [bounds.min.x, bounds.max.x].contains(&coord.x)
+            || [bounds.min.y, bounds.max.y].contains(&coord.y)
+        {
+            self.bounds_stale.set(true);
+        }
+
+        Some(removed)
+    }
+
+    /// The smallest box containing every occupied cell. For an empty grid
+    /// this is the empty `Bounds::new()`.
+    pub fn bounds(&self) -> Bounds {
+        if self.bounds_stale.get() {
+            self.bounds.set(self.cells.keys().coord_bounds());
+            self.bounds_stale.set(false);
+        }
+
+        self.bounds.get()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(&coord, value)| (coord, value))
+    }
+
+    pub fn coords(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.cells.keys().copied()
+    }
+
+    pub fn values(&self) -> hash_map::Values<'_, Coord, T> {
+        self.cells.values()
+    }
+
+    /// Renders the grid within its bounds, asking `glyph` for the character
+    /// of every cell, occupied or not.
+    pub fn render<F>(&self, glyph: F) -> Render<'_, T, F>
+    where
+        F: Fn(Coord, Option<&T>) -> char,
+    {
+        Render {
+            grid: self,
+            glyph,
+            include: Bounds::new(),
+            padding: 0,
+            axis_labels: false,
+        }
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        grid.extend(iter);
+        grid
+    }
+}
+
+impl<T> Extend<(Coord, T)> for SparseGrid<T> {
+    fn extend<I: IntoIterator<Item = (Coord, T)>>(&mut self, iter: I) {
+        for (coord, value) in iter {
+            self.insert(coord, value);
+        }
+    }
+}
+
+impl FromIterator<Coord> for SparseGrid<()> {
+    fn from_iter<I: IntoIterator<Item = Coord>>(iter: I) -> Self {
+        iter.into_iter().map(|coord| (coord, ())).collect()
+    }
+}
+
+pub struct Render<'a, T, F> {
+    grid: &'a SparseGrid<T>,
+    glyph: F,
+    include: Bounds,
+    padding: usize,
+    axis_labels: bool,
+}
+
+impl<T, F> Render<'_, T, F> {
+    /// Extends the rendered area so that it contains `coord`.
+    pub fn include(mut self, coord: Coord) -> Self {
+        self.include = self.include.with(&coord);
+        self
+    }
+
+    /// Adds `padding` empty cells around every side of the rendered area.
+    pub fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Prints the y coordinates to the left of every row and the x
+    /// coordinates of the first, last and every tenth column on top.
+    pub fn axis_labels(mut self, axis_labels: bool) -> Self {
+        self.axis_labels = axis_labels;
+        self
+    }
+
+    fn area(&self) -> Bounds {
+        self.grid
+            .bounds()
+            .union(self.include)
+            .expand(self.padding as isize)
+    }
+
+    fn write_x_labels(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        area: Bounds,
+        y_label_width: usize,
+    ) -> std::fmt::Result {
+        let labels: Vec<String> = (area.min.x..=area.max.x)
+            .map(
+                |x| match x == area.min.x || x == area.max.x || x % 10 == 0 {
+                    true => x.to_string(),
+                    false => String::new(),
+                },
+            )
+            .collect();
+
+        let height = labels.iter().map(|l| l.len()).max().unwrap_or(0);
+        for row in 0..height {
+            write!(f, "{:width$} ", "", width = y_label_width)?;
+            for label in labels.iter() {
+                let ch = (row + label.len())
+                    .checked_sub(height)
+                    .map_or(' ', |idx| label.as_bytes()[idx] as char);
+
+                write!(f, "{}", ch)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<T, F> Display for Render<'_, T, F>
+where
+    F: Fn(Coord, Option<&T>) -> char,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.grid.is_empty() && self.include.is_empty() {
+            return Ok(());
+        }
+
+        let area = self.area();
+        let y_label_width = [area.min.y, area.max.y]
+            .map(|y| y.to_string().len())
+            .into_iter()
+            .max()
+            .unwrap();
+
+        if self.axis_labels {
+            self.write_x_labels(f, area, y_label_width)?;
+        }
+
+        for y in area.min.y..=area.max.y {
+            if self.axis_labels {
+                write!(f, "{:>width$} ", y, width = y_label_width)?;
+            }
+
+            for x in area.min.x..=area.max.x {
+                let coord = Coord::new(x, y);
+                write!(f, "{}", (self.glyph)(coord, self.grid.get(coord)))?;
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SparseGrid;
+    use crate::coord::Coord;
+
+    #[test]
+    fn bounds_follow_removals() {
+        let mut grid: SparseGrid<()> = [Coord::new(0, 0), Coord::new(3, -2), Coord::new(1, 5)]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.bounds().min, Coord::new(0, -2));
+        assert_eq!(grid.bounds().max, Coord::new(3, 5));
+
+        grid.remove(Coord::new(1, 5));
+        assert_eq!(grid.bounds().max, Coord::new(3, 0));
+
+        grid.remove(Coord::new(0, 0));
+        grid.remove(Coord::new(3, -2));
+        assert!(grid.bounds().is_empty());
+    }
+
+    #[test]
+    fn render() {
+        let grid: SparseGrid<()> = [Coord::new(0, 0), Coord::new(2, 1)].into_iter().collect();
+        let glyph = |_, cell: Option<&()>| if cell.is_some() { '#' } else { '.' };
+
+        assert_eq!(grid.render(glyph).to_string(), "#..\n..#\n");
+        assert_eq!(
+            grid.render(glyph).padding(1).axis_labels(true).to_string(),
+            "   -    \n   10  3\n-1 .....\n 0 .#...\n 1 ...#.\n 2 .....\n"
+        );
+    }
+}
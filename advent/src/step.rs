@@ -0,0 +1,35 @@
+//! Simulations that can be advanced one state at a time, to watch them
+//! unfold with `aoc step` instead of recompiling with prints in them.
+
+use std::fmt::Display;
+
+use crate::Result;
+
+/// A simulation, shown through its `Display` impl after every step.
+pub trait Steppable: Display {
+    /// Moves on to the next state. Returns `false`, leaving the state as it
+    /// was, once the simulation is over.
+    fn step(&mut self) -> Result<bool>;
+
+    /// The character marking where the action is in the displayed state,
+    /// like a falling rock, for the viewport to follow. Without one, it
+    /// follows whatever changed in the last step.
+    fn marker(&self) -> Option<char> {
+        None
+    }
+}
+
+/// A simulation built by a day, owning everything it needs.
+pub type Simulation = Box<dyn Steppable>;
+
+/// Takes up to `steps` steps, stopping early at the end of the simulation.
+/// Returns the number of steps taken.
+pub fn advance(simulation: &mut dyn Steppable, steps: usize) -> Result<usize> {
+    for taken in 0..steps {
+        if !simulation.step()? {
+            return Ok(taken);
+        }
+    }
+
+    Ok(steps)
+}
@@ -0,0 +1,49 @@
+//! The counting allocator counts every thread while measuring, so it gets a
+//! test binary of its own, with no other test running alongside.
+
+use std::{hint::black_box, thread};
+
+use advent::memory::{measure, Counting, Usage};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn counting() {
+    let (_, usage) = measure(|| black_box(vec![0u8; 1000]));
+    assert_eq!(
+        usage,
+        Usage {
+            allocations: 1,
+            bytes: 1000,
+            peak_bytes: 1000,
+        }
+    );
+
+    let (kept, usage) = measure(|| {
+        let mut v = Vec::with_capacity(4);
+        v.extend([1u32; 4]);
+        v.push(5);
+        drop(black_box(vec![0u8; 100]));
+        v
+    });
+    assert_eq!(usage.allocations, 3);
+    assert_eq!(usage.bytes, 16 + 32 + 100);
+    assert_eq!(usage.peak_bytes, 132);
+
+    let (_, usage) = measure(|| drop(kept));
+    assert_eq!(usage, Usage::default());
+
+    let ((), usage) = measure(|| {});
+    assert_eq!(usage.allocations, 0);
+
+    // Spawning takes a few allocations of its own.
+    let (_, usage) = measure(|| {
+        thread::spawn(|| black_box(vec![0u8; 1 << 20]))
+            .join()
+            .unwrap()
+    });
+    assert!(usage.allocations > 1);
+    assert!(usage.bytes > 1 << 20);
+    assert!(usage.peak_bytes > 1 << 20);
+}
@@ -0,0 +1,455 @@
+use advent::{
+    image::ImageOptions, input::Source, log, params::Overrides, record::RecordOptions,
+    report::Format, usage, Result,
+};
+
+pub const USAGE: &str = "\
+Usage: aoc run <day|all> [--part <1|2>] [--jobs <n>] [--mem]
+               [--record <path> ...] [--images <dir> ...]
+               [--format <text|json>] [options]
+       aoc bench <day|all> [--runs <n>] [--json <path>]
+                 [--baseline <path> [--threshold <percent>]] [options]
+       aoc verify <day|all> [--answers <path>]
+       aoc step <day> [--part <1|2>] [options]
+
+Runs the solution of the given day, or of every day in order. Without
+input options, each day's bundled in.txt is used. The simulations of a
+single day can be recorded, and replayed with asciinema, and some days
+draw pictures of their states. With --format json, each day is printed
+as one JSON object per line, with its typed answers, the time it took, a
+hash of its input and the parameters it used. With --jobs, the parts are
+solved on that many threads and summed up in a table, a panicking part
+only failing itself. With --mem, the allocations, bytes allocated and peak
+bytes live of the parsing and each part are counted and printed after the
+answers.
+
+bench times the parsing and each part, and prints their min, median and
+mean. It can save them as JSON, and compare them to a saved baseline,
+failing if a median got slower by more than the threshold (10% by default).
+
+verify solves the bundled in.txt and the sample of each day, and checks the
+answers against aoc/answers.json, or the given answers file.
+
+step shows the simulation of a day's part, the first by default, in the
+terminal. It can be run, paused, taken a step or jumped to any step, and
+follows the action unless panned around with the arrows.
+
+Options:";
+
+/// What to run, as given on the command line.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Command {
+    Run {
+        selection: Selection,
+        part: Option<u8>,
+        record: RecordOptions,
+        images: ImageOptions,
+        format: Format,
+        /// The number of threads to solve the parts on, if several days or
+        /// parts are solved at once.
+        jobs: Option<usize>,
+        /// Whether to count what each stage allocates.
+        mem: bool,
+    },
+    Bench {
+        selection: Selection,
+        runs: usize,
+        json: Option<String>,
+        baseline: Option<String>,
+        threshold_percent: u32,
+    },
+    Verify {
+        selection: Selection,
+        answers: Option<String>,
+    },
+    Step {
+        selection: Selection,
+        part: u8,
+    },
+    Help,
+}
+
+/// The days to run, and what to run them on.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Selection {
+    pub days: Days,
+    pub source: Source,
+    pub overrides: Overrides,
+    /// The number of `-v`s.
+    pub verbosity: u8,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Days {
+    One(usize),
+    All,
+}
+
+fn parse_value<T: std::str::FromStr>(
+    arg: &str,
+    args: &mut impl Iterator<Item = String>,
+) -> Result<T> {
+    let value = args
+        .next()
+        .ok_or_else(|| usage!("Missing the value after {}", arg))?;
+
+    value
+        .parse()
+        .map_err(|_| usage!("Invalid value for {}: {}", arg, value))
+}
+
+pub fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Command> {
+    let mut args = args.into_iter();
+
+    let command = match args.next() {
+        Some(command) if ["run", "bench", "verify", "step"].contains(&command.as_str()) => command,
+        Some(command) if ["help", "-h", "--help"].contains(&command.as_str()) => {
+            return Ok(Command::Help)
+        }
+        None => return Ok(Command::Help),
+        Some(command) => return Err(usage!("Unknown command {}", command)),
+    };
+
+    let days = match args.next().as_deref() {
+        Some("all") => Days::All,
+        Some(day) => match day.parse() {
+            Ok(day @ 1..=25) => Days::One(day),
+            _ => return Err(usage!("{} isn't a day between 1 and 25", day)),
+        },
+        None => return Err(usage!("Missing the day to {}", command)),
+    };
+
+    let mut selection = Selection {
+        days,
+        source: Source::Bundled,
+        overrides: Overrides::default(),
+        verbosity: 0,
+    };
+
+    let mut part = None;
+    let mut record = RecordOptions::default();
+    let mut images = ImageOptions::default();
+    let mut format = Format::default();
+    let mut jobs = None;
+    let mut mem = false;
+    let mut runs = 10;
+    let mut json = None;
+    let mut baseline = None;
+    let mut threshold_percent = 10;
+    let mut answers = None;
+
+    while let Some(arg) = args.next() {
+        if log::parse_verbosity(&arg, &mut selection.verbosity) {
+            continue;
+        }
+
+        if let Some(parsed) = Source::parse_option(&arg, &mut args) {
+            selection.source = parsed?;
+            continue;
+        }
+
+        if let Some(res) = selection.overrides.parse_option(&arg, &mut args) {
+            res?;
+            continue;
+        }
+
+        if command == "run" {
+            if let Some(res) = record.parse_option(&arg, &mut args) {
+                res?;
+                continue;
+            }
+
+            if let Some(res) = images.parse_option(&arg, &mut args) {
+                res?;
+                continue;
+            }
+
+            if let Some(res) = Format::parse_option(&arg, &mut args) {
+                format = res?;
+                continue;
+            }
+        }
+
+        match (command.as_str(), arg.as_str()) {
+            ("run" | "step", "--part") => match parse_value(&arg, &mut args)? {
+                p @ (1 | 2) => part = Some(p),
+                p => return Err(usage!("{} isn't a part", p)),
+            },
+            ("run", "--jobs") => match parse_value(&arg, &mut args)? {
+                0 => return Err(usage!("There must be at least one job")),
+                n => jobs = Some(n),
+            },
+            ("run", "--mem") => mem = true,
+            ("bench", "--runs") => match parse_value(&arg, &mut args)? {
+                0 => return Err(usage!("There must be at least one run")),
+                n => runs = n,
+            },
+            ("bench", "--json") => json = Some(parse_value(&arg, &mut args)?),
+            ("bench", "--baseline") => baseline = Some(parse_value(&arg, &mut args)?),
+            ("bench", "--threshold") => threshold_percent = parse_value(&arg, &mut args)?,
+            ("verify", "--answers") => answers = Some(parse_value(&arg, &mut args)?),
+            _ => return Err(usage!("Unknown argument {}", arg)),
+        }
+    }
+
+    if command == "verify"
+        && (selection.source != Source::Bundled || selection.overrides != Overrides::default())
+    {
+        return Err(usage!(
+            "verify checks the bundled inputs with the default parameters"
+        ));
+    }
+
+    if command == "step" && selection.days == Days::All {
+        return Err(usage!("Only a single day can be stepped through"));
+    }
+
+    if jobs.is_some() {
+        if record.path.is_some() {
+            return Err(usage!("--record can't be used with --jobs"));
+        }
+
+        if images.dir.is_some() {
+            return Err(usage!("--images can't be used with --jobs"));
+        }
+
+        if format == Format::Json {
+            return Err(usage!("--jobs prints a table, it can't print JSON"));
+        }
+
+        if mem {
+            return Err(usage!(
+                "--mem counts the stages of one day at a time, without --jobs"
+            ));
+        }
+    }
+
+    if selection.days == Days::All {
+        if record.path.is_some() {
+            return Err(usage!("Only a single day can be recorded"));
+        }
+
+        if matches!(selection.source, Source::Stdin | Source::File(_)) {
+            return Err(usage!("Only the bundled inputs can be used for all days"));
+        }
+
+        if selection.overrides.has_args() {
+            return Err(usage!(
+                "--param needs a single day, use --config for all days"
+            ));
+        }
+    }
+
+    Ok(match command.as_str() {
+        "run" => Command::Run {
+            selection,
+            part,
+            record,
+            images,
+            format,
+            jobs,
+            mem,
+        },
+        "verify" => Command::Verify { selection, answers },
+        "step" => Command::Step {
+            selection,
+            part: part.unwrap_or(1),
+        },
+        _ => Command::Bench {
+            selection,
+            runs,
+            json,
+            baseline,
+            threshold_percent,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        image::ImageOptions, input::Source, params::Overrides, record::RecordOptions,
+        report::Format,
+    };
+
+    use super::{parse_args, Command, Days, Selection};
+
+    fn parse(args: &str) -> advent::Result<Command> {
+        parse_args(args.split_whitespace().map(String::from))
+    }
+
+    #[test]
+    fn run() {
+        let mut overrides = Overrides::default();
+        overrides.add_arg("rounds=500").unwrap();
+
+        assert_eq!(
+            parse("run 14 --part 2 --input path --param rounds=500"),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: Days::One(14),
+                    source: Source::File("path".to_string()),
+                    overrides,
+                    verbosity: 0,
+                },
+                part: Some(2),
+                record: RecordOptions::default(),
+                images: ImageOptions::default(),
+                format: Format::default(),
+                jobs: None,
+                mem: false,
+            })
+        );
+
+        assert_eq!(
+            parse("run all --sample --format json"),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: Days::All,
+                    source: Source::Sample,
+                    overrides: Overrides::default(),
+                    verbosity: 0,
+                },
+                part: None,
+                record: RecordOptions::default(),
+                images: ImageOptions::default(),
+                format: Format::Json,
+                jobs: None,
+                mem: false,
+            })
+        );
+
+        assert_eq!(
+            parse("run 17 --record out.cast --max-frames 50"),
+            Ok(Command::Run {
+                selection: Selection {
+                    days: Days::One(17),
+                    source: Source::Bundled,
+                    overrides: Overrides::default(),
+                    verbosity: 0,
+                },
+                part: None,
+                record: RecordOptions {
+                    path: Some("out.cast".to_string()),
+                    max_frames: Some(50),
+                    ..RecordOptions::default()
+                },
+                images: ImageOptions::default(),
+                format: Format::default(),
+                jobs: None,
+                mem: false,
+            })
+        );
+    }
+
+    #[test]
+    fn jobs() {
+        let jobs = |args| match parse(args) {
+            Ok(Command::Run { jobs, .. }) => jobs,
+            _ => panic!("{} should be a run", args),
+        };
+
+        assert_eq!(jobs("run all"), None);
+        assert_eq!(jobs("run all --jobs 4 --part 1"), Some(4));
+    }
+
+    #[test]
+    fn mem() {
+        assert!(matches!(
+            parse("run 16 --mem --format json"),
+            Ok(Command::Run { mem: true, .. })
+        ));
+        assert!(matches!(
+            parse("run 16"),
+            Ok(Command::Run { mem: false, .. })
+        ));
+    }
+
+    #[test]
+    fn bench() {
+        assert_eq!(
+            parse("bench all --runs 5 --json out.json --baseline base.json --threshold 20"),
+            Ok(Command::Bench {
+                selection: Selection {
+                    days: Days::All,
+                    source: Source::Bundled,
+                    overrides: Overrides::default(),
+                    verbosity: 0,
+                },
+                runs: 5,
+                json: Some("out.json".to_string()),
+                baseline: Some("base.json".to_string()),
+                threshold_percent: 20,
+            })
+        );
+    }
+
+    #[test]
+    fn verify() {
+        assert_eq!(
+            parse("verify 19 --answers answers.json -vv"),
+            Ok(Command::Verify {
+                selection: Selection {
+                    days: Days::One(19),
+                    source: Source::Bundled,
+                    overrides: Overrides::default(),
+                    verbosity: 2,
+                },
+                answers: Some("answers.json".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn step() {
+        assert_eq!(
+            parse("step 14 --part 2 --sample"),
+            Ok(Command::Step {
+                selection: Selection {
+                    days: Days::One(14),
+                    source: Source::Sample,
+                    overrides: Overrides::default(),
+                    verbosity: 0,
+                },
+                part: 2,
+            })
+        );
+        assert!(matches!(
+            parse("step 17 --param width=9"),
+            Ok(Command::Step { part: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(parse(""), Ok(Command::Help));
+        assert!(parse("walk 1").is_err());
+        assert!(parse("run 26").is_err());
+        assert!(parse("run 1 --part 3").is_err());
+        assert!(parse("run 1 --input").is_err());
+        assert!(parse("run 1 --runs 3").is_err());
+        assert!(parse("run all --input path").is_err());
+        assert!(parse("run all --param rounds=500").is_err());
+        assert!(parse("run all --record out.cast").is_err());
+        assert!(parse("bench 1 --part 1").is_err());
+        assert!(parse("bench 1 --record out.cast").is_err());
+        assert!(parse("bench 1 --images out").is_err());
+        assert!(parse("bench 1 --format json").is_err());
+        assert!(parse("run 1 --format yaml").is_err());
+        assert!(parse("run all --jobs 0").is_err());
+        assert!(parse("run all --jobs 4 --format json").is_err());
+        assert!(parse("run 17 --jobs 2 --record out.cast").is_err());
+        assert!(parse("run all --jobs 2 --images out").is_err());
+        assert!(parse("bench all --jobs 4").is_err());
+        assert!(parse("bench 1 --runs 0").is_err());
+        assert!(parse("bench 1 --threshold many").is_err());
+        assert!(parse("verify all --sample").is_err());
+        assert!(parse("verify 11 --param rounds=5").is_err());
+        assert!(parse("run 1 --answers answers.json").is_err());
+        assert!(parse("run all --jobs 2 --mem").is_err());
+        assert!(parse("bench 1 --mem").is_err());
+        assert!(parse("step all").is_err());
+        assert!(parse("step 14 --record out.cast").is_err());
+        assert!(parse("step 14 --jobs 2").is_err());
+    }
+}
@@ -0,0 +1,210 @@
+//! Timing of each day's parsing and parts, with reports that can be saved
+//! as JSON and compared to a baseline.
+
+use std::{
+    fmt::Display,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use advent::{params::Overrides, solution::Solution, Result};
+use serde::{Deserialize, Serialize};
+
+/// The wall time of each run of a stage, like `parse` or `part1`.
+pub type Samples = Vec<(&'static str, Vec<Duration>)>;
+
+fn time_runs<T>(runs: usize, mut f: impl FnMut() -> Result<T>) -> Result<(T, Vec<Duration>)> {
+    let mut times = Vec::with_capacity(runs);
+    let mut last = None;
+
+    for _ in 0..runs {
+        let start = Instant::now();
+        let res = black_box(f()?);
+        times.push(start.elapsed());
+        last = Some(res);
+    }
+
+    Ok((last.expect("There is at least one run"), times))
+}
+
+/// Runs the parsing, then each part on the parsed input, `runs` times.
+pub fn time<S: Solution>(input: &str, overrides: &Overrides, runs: usize) -> Result<Samples> {
+    let (parsed, parse) = time_runs(runs, || S::parse_with(input, overrides))?;
+    let (_, part1) = time_runs(runs, || S::part1(&parsed))?;
+
+    let mut samples = vec![("parse", parse), ("part1", part1)];
+    if S::PARTS > 1 {
+        let (_, part2) = time_runs(runs, || S::part2(&parsed))?;
+        samples.push(("part2", part2));
+    }
+
+    Ok(samples)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub day: usize,
+    pub stage: String,
+    pub runs: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Entry {
+    pub fn new(day: usize, stage: &str, times: &[Duration]) -> Entry {
+        let mut nanos: Vec<u64> = times.iter().map(|t| t.as_nanos() as u64).collect();
+        nanos.sort_unstable();
+
+        let mid = nanos.len() / 2;
+        let median = if nanos.len().is_multiple_of(2) {
+            (nanos[mid - 1] + nanos[mid]) / 2
+        } else {
+            nanos[mid]
+        };
+
+        Entry {
+            day,
+            stage: stage.to_string(),
+            runs: nanos.len(),
+            min_ns: nanos[0],
+            median_ns: median,
+            mean_ns: nanos.iter().sum::<u64>() / nanos.len() as u64,
+        }
+    }
+
+    /// How much slower the stage got since the baseline, as a ratio of the
+    /// medians. `None` if the baseline doesn't have it.
+    pub fn change_from(&self, baseline: &Report) -> Option<f64> {
+        let base = baseline
+            .entries
+            .iter()
+            .find(|b| b.day == self.day && b.stage == self.stage)?;
+
+        Some(self.median_ns as f64 / base.median_ns.max(1) as f64)
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub entries: Vec<Entry>,
+}
+
+impl Report {
+    /// The stages whose median got slower than the baseline by more than
+    /// `threshold`, a fraction.
+    pub fn regressions<'a>(
+        &'a self,
+        baseline: &'a Report,
+        threshold: f64,
+    ) -> impl Iterator<Item = &'a Entry> + 'a {
+        self.entries.iter().filter(move |entry| {
+            entry
+                .change_from(baseline)
+                .is_some_and(|ratio| ratio > 1. + threshold)
+        })
+    }
+}
+
+fn ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+/// The report as a table, with the change from the baseline if any.
+pub struct Table<'a> {
+    pub report: &'a Report,
+    pub baseline: Option<&'a Report>,
+    pub threshold: f64,
+}
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<4} {:<6} {:>5} {:>12} {:>12} {:>12}",
+            "Day", "Stage", "Runs", "Min", "Median", "Mean"
+        )?;
+        if self.baseline.is_some() {
+            write!(f, " {:>9}", "Change")?;
+        }
+        writeln!(f)?;
+
+        for entry in self.report.entries.iter() {
+            write!(
+                f,
+                "{:<4} {:<6} {:>5} {:>12} {:>12} {:>12}",
+                format!("{:02}", entry.day),
+                entry.stage,
+                entry.runs,
+                ns(entry.min_ns),
+                ns(entry.median_ns),
+                ns(entry.mean_ns),
+            )?;
+
+            if let Some(baseline) = self.baseline {
+                match entry.change_from(baseline) {
+                    Some(ratio) => {
+                        let percent = format!("{:+.1}%", (ratio - 1.) * 100.);
+                        write!(f, " {:>9}", percent)?;
+                        if ratio > 1. + self.threshold {
+                            write!(f, "  REGRESSION")?;
+                        }
+                    }
+                    None => write!(f, " {:>9}", "new")?,
+                }
+            }
+
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Entry, Report};
+
+    fn entry(day: usize, median_ns: u64) -> Entry {
+        Entry {
+            day,
+            stage: "part1".to_string(),
+            runs: 1,
+            min_ns: median_ns,
+            median_ns,
+            mean_ns: median_ns,
+        }
+    }
+
+    #[test]
+    fn stats() {
+        let times = [5, 1, 3, 100].map(Duration::from_nanos);
+        let entry = Entry::new(7, "parse", &times);
+
+        assert_eq!(entry.min_ns, 1);
+        assert_eq!(entry.median_ns, 4);
+        assert_eq!(entry.mean_ns, 27);
+        assert_eq!(entry.runs, 4);
+    }
+
+    #[test]
+    fn regressions() {
+        let report = Report {
+            entries: vec![entry(1, 150), entry(2, 105), entry(3, 10)],
+        };
+        let baseline = Report {
+            entries: vec![entry(1, 100), entry(2, 100)],
+        };
+
+        assert_eq!(report.entries[0].change_from(&baseline), Some(1.5));
+        assert_eq!(report.entries[2].change_from(&baseline), None);
+
+        let regressed: Vec<_> = report
+            .regressions(&baseline, 0.1)
+            .map(|entry| entry.day)
+            .collect();
+        assert_eq!(regressed, vec![1]);
+    }
+}
@@ -0,0 +1,105 @@
+use advent::{
+    input::Bundled,
+    memory::Memory,
+    params::Overrides,
+    solution::{simulate_with, solve_counting, solve_with, Answers, Solution},
+    step::Simulation,
+    Result,
+};
+
+use crate::bench::{self, Samples};
+
+/// Solves a day, also counting what each stage allocates.
+type Count = fn(&str, Option<u8>, &Overrides) -> Result<(Answers, Memory)>;
+
+/// A day's solver, with its bundled inputs.
+pub struct Day {
+    pub number: usize,
+    pub bundled: Bundled,
+    /// The number of parts, only one on the last day.
+    pub parts: u8,
+    solve: fn(&str, Option<u8>, &Overrides) -> Result<Answers>,
+    count: Count,
+    time: fn(&str, &Overrides, usize) -> Result<Samples>,
+    simulate: fn(&str, u8, &Overrides) -> Result<Simulation>,
+}
+
+impl Day {
+    const fn new<S: Solution>(number: usize, bundled: Bundled) -> Day {
+        Day {
+            number,
+            bundled,
+            parts: S::PARTS,
+            solve: solve_with::<S>,
+            count: solve_counting::<S>,
+            time: bench::time::<S>,
+            simulate: simulate_with::<S>,
+        }
+    }
+
+    pub fn solve(&self, input: &str, part: Option<u8>, overrides: &Overrides) -> Result<Answers> {
+        (self.solve)(input, part, overrides)
+    }
+
+    /// Same as [`Day::solve`], also counting what each stage allocates.
+    pub fn count(
+        &self,
+        input: &str,
+        part: Option<u8>,
+        overrides: &Overrides,
+    ) -> Result<(Answers, Memory)> {
+        (self.count)(input, part, overrides)
+    }
+
+    pub fn time(&self, input: &str, overrides: &Overrides, runs: usize) -> Result<Samples> {
+        (self.time)(input, overrides, runs)
+    }
+
+    pub fn simulate(&self, input: &str, part: u8, overrides: &Overrides) -> Result<Simulation> {
+        (self.simulate)(input, part, overrides)
+    }
+}
+
+macro_rules! days {
+    ( $( $number:literal => $krate:ident :: $solution:ident ),* $(,)? ) => {
+        pub const DAYS: [Day; 25] = [
+            $(
+                Day::new::<$krate::$solution>(
+                    $number,
+                    Bundled {
+                        input: include_str!(concat!("../../", stringify!($krate), "/in.txt")),
+                        sample: include_str!(concat!("../../", stringify!($krate), "/small-in.txt")),
+                    },
+                ),
+            )*
+        ];
+    };
+}
+
+days! {
+    1 => day01::Day01,
+    2 => day02::Day02,
+    3 => day03::Day03,
+    4 => day04::Day04,
+    5 => day05::Day05,
+    6 => day06::Day06,
+    7 => day07::Day07,
+    8 => day08::Day08,
+    9 => day09::Day09,
+    10 => day10::Day10,
+    11 => day11::Day11,
+    12 => day12::Day12,
+    13 => day13::Day13,
+    14 => day14::Day14,
+    15 => day15::Day15,
+    16 => day16::Day16,
+    17 => day17::Day17,
+    18 => day18::Day18,
+    19 => day19::Day19,
+    20 => day20::Day20,
+    21 => day21::Day21,
+    22 => day22::Day22,
+    23 => day23::Day23,
+    24 => day24::Day24,
+    25 => day25::Day25,
+}
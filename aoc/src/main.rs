@@ -0,0 +1,377 @@
+//! One entry point for every day: `aoc run 14 --part 2 --input path`,
+//! `aoc run all`, `aoc bench all` to time them, `aoc verify all` to check
+//! them against the recorded answers, or `aoc step 14` to watch a simulation.
+
+use std::{env, fs, process::ExitCode};
+
+use advent::{
+    image::ImageOptions,
+    info,
+    input::Source,
+    log,
+    memory::{self, Counting},
+    params::Overrides,
+    record::RecordOptions,
+    report::{self, Format},
+    solution::print_answers,
+    Result,
+};
+
+use args::{parse_args, Command, Days, Selection, USAGE};
+use bench::{Entry, Report, Table};
+use days::{Day, DAYS};
+use verify::{verify, Answers};
+
+mod args;
+mod bench;
+mod days;
+mod parallel;
+mod stepper;
+mod verify;
+
+/// Only counts while `--mem` measures a stage.
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+impl Selection {
+    fn days(&self) -> &'static [Day] {
+        match self.days {
+            Days::One(day) => &DAYS[day - 1..day],
+            Days::All => &DAYS[..],
+        }
+    }
+
+    /// The days to run for `part`. `all` leaves out the days without that
+    /// part, like `--jobs` does, while a single day is kept so that asking it
+    /// for a part it lacks fails.
+    fn days_with(&self, part: Option<u8>) -> Vec<&'static Day> {
+        let days = self.days().iter();
+        match self.days {
+            Days::One(_) => days.collect(),
+            Days::All => days
+                .filter(|day| part.is_none_or(|part| part <= day.parts))
+                .collect(),
+        }
+    }
+}
+
+fn run(
+    selection: &Selection,
+    part: Option<u8>,
+    record: &RecordOptions,
+    images: &ImageOptions,
+    format: Format,
+    mem: bool,
+) -> ExitCode {
+    let days = selection.days_with(part);
+    let mut status = ExitCode::SUCCESS;
+
+    for day in &days {
+        if days.len() > 1 && format == Format::Text {
+            println!("Day {:02}", day.number);
+        }
+
+        let solved = selection.source.read(day.bundled).and_then(|input| {
+            let mut memory = Vec::new();
+            let mut solved = report::Report::measure(&input, || {
+                record.record(|| {
+                    images.export(|| {
+                        if !mem {
+                            return day.solve(&input, part, &selection.overrides);
+                        }
+
+                        let (answers, usage) = day.count(&input, part, &selection.overrides)?;
+                        memory = usage;
+                        Ok(answers)
+                    })
+                })
+            });
+            solved.day = Some(day.number);
+            solved.memory = memory;
+
+            if format == Format::Json {
+                println!("{}", solved.json());
+            }
+            solved.answers.map(|answers| (answers, solved.memory))
+        });
+
+        match solved {
+            Ok((answers, memory)) if format == Format::Text => {
+                print_answers(&answers);
+                if mem {
+                    print!("{}", memory::Table(&memory));
+                }
+            }
+            Ok(_) => {}
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day.number, err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    status
+}
+
+fn run_parallel(selection: &Selection, part: Option<u8>, jobs: usize) -> ExitCode {
+    let tasks = parallel::tasks(selection.days(), part);
+    info!("Solving {} parts on {} threads...", tasks.len(), jobs);
+
+    let outcomes = parallel::run(&tasks, jobs, |task| {
+        let input = selection.source.read(task.day.bundled)?;
+        let mut answers = task
+            .day
+            .solve(&input, Some(task.part), &selection.overrides)?;
+        Ok(answers.remove(0).1)
+    });
+    print!("{}", parallel::Table(&outcomes));
+
+    if outcomes.iter().any(|outcome| outcome.failed()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn read_report(path: &str) -> Result<Report, String> {
+    let json = fs::read_to_string(path)
+        .map_err(|err| format!("Failed to read the baseline {}: {}", path, err))?;
+    serde_json::from_str(&json).map_err(|err| format!("Invalid baseline {}: {}", path, err))
+}
+
+fn write_report(path: &str, report: &Report) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(report).expect("Reports can always be serialized");
+    fs::write(path, json + "\n").map_err(|err| format!("Failed to write {}: {}", path, err))
+}
+
+fn bench(
+    selection: &Selection,
+    runs: usize,
+    json: Option<&str>,
+    baseline: Option<&str>,
+    threshold_percent: u32,
+) -> Result<ExitCode, String> {
+    let baseline = baseline.map(read_report).transpose()?;
+    let mut report = Report::default();
+    let mut status = ExitCode::SUCCESS;
+
+    for day in selection.days() {
+        info!("Timing day {:02}...", day.number);
+
+        let samples = selection
+            .source
+            .read(day.bundled)
+            .and_then(|input| day.time(&input, &selection.overrides, runs));
+
+        match samples {
+            Ok(samples) => report.entries.extend(
+                samples
+                    .iter()
+                    .map(|(stage, times)| Entry::new(day.number, stage, times)),
+            ),
+            Err(err) => {
+                eprintln!("Day {:02}: {}", day.number, err);
+                status = ExitCode::FAILURE;
+            }
+        }
+    }
+
+    let threshold = threshold_percent as f64 / 100.;
+    print!(
+        "{}",
+        Table {
+            report: &report,
+            baseline: baseline.as_ref(),
+            threshold,
+        }
+    );
+
+    if let Some(path) = json {
+        write_report(path, &report)?;
+    }
+
+    if let Some(baseline) = &baseline {
+        let regressions = report.regressions(baseline, threshold).count();
+        if regressions > 0 {
+            eprintln!(
+                "{} stages got slower by more than {}%",
+                regressions, threshold_percent
+            );
+            status = ExitCode::FAILURE;
+        }
+    }
+
+    Ok(status)
+}
+
+fn verify_days(selection: &Selection, answers: Option<&str>) -> Result<ExitCode, String> {
+    let answers = match answers {
+        Some(path) => Answers::parse(
+            &fs::read_to_string(path)
+                .map_err(|err| format!("Failed to read the answers {}: {}", path, err))?,
+        )?,
+        None => Answers::parse(Answers::BUNDLED)?,
+    };
+
+    let checks: Vec<_> = selection
+        .days()
+        .iter()
+        .flat_map(|day| {
+            info!("Verifying day {:02}...", day.number);
+            verify(day, &answers)
+        })
+        .collect();
+
+    print!("{}", verify::Table(&checks));
+
+    Ok(if checks.iter().any(|check| check.failed()) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    })
+}
+
+fn step(selection: &Selection, part: u8) -> Result<ExitCode, String> {
+    let day = &selection.days()[0];
+    let input = selection
+        .source
+        .read(day.bundled)
+        .map_err(|err| err.to_string())?;
+    let simulate = || day.simulate(&input, part, &selection.overrides);
+    let simulation = simulate().map_err(|err| format!("Day {:02}: {}", day.number, err))?;
+
+    let title = format!("Day {:02} part {}", day.number, part);
+    let step = stepper::run(title.clone(), simulation, &simulate)
+        .map_err(|err| format!("Failed to use the terminal: {}", err))?;
+    println!("{}: left at step {}", title, step);
+
+    Ok(ExitCode::SUCCESS)
+}
+
+fn main() -> ExitCode {
+    let command = match parse_args(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(err) => {
+            eprintln!(
+                "{}\n\n{}\n{}\n{}\n{}\n{}\n{}\n{}",
+                err,
+                USAGE,
+                Source::USAGE,
+                Overrides::USAGE,
+                RecordOptions::USAGE,
+                ImageOptions::USAGE,
+                Format::USAGE,
+                log::USAGE
+            );
+            return ExitCode::from(2);
+        }
+    };
+
+    let verbosity = match &command {
+        Command::Run { selection, .. }
+        | Command::Bench { selection, .. }
+        | Command::Verify { selection, .. }
+        | Command::Step { selection, .. } => selection.verbosity,
+        Command::Help => 0,
+    };
+    if let Err(err) = log::init(verbosity) {
+        eprintln!("{}", err);
+        return ExitCode::from(2);
+    }
+
+    match command {
+        Command::Help => {
+            println!(
+                "{}\n{}\n{}\n{}\n{}\n{}\n{}",
+                USAGE,
+                Source::USAGE,
+                Overrides::USAGE,
+                RecordOptions::USAGE,
+                ImageOptions::USAGE,
+                Format::USAGE,
+                log::USAGE
+            );
+            ExitCode::SUCCESS
+        }
+        Command::Run {
+            selection,
+            part,
+            record,
+            images,
+            format,
+            jobs: None,
+            mem,
+        } => run(&selection, part, &record, &images, format, mem),
+        Command::Run {
+            selection,
+            part,
+            jobs: Some(jobs),
+            ..
+        } => run_parallel(&selection, part, jobs),
+        Command::Bench {
+            selection,
+            runs,
+            json,
+            baseline,
+            threshold_percent,
+        } => bench(
+            &selection,
+            runs,
+            json.as_deref(),
+            baseline.as_deref(),
+            threshold_percent,
+        )
+        .unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }),
+        Command::Verify { selection, answers } => verify_days(&selection, answers.as_deref())
+            .unwrap_or_else(|err| {
+                eprintln!("{}", err);
+                ExitCode::FAILURE
+            }),
+        Command::Step { selection, part } => step(&selection, part).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{params::Overrides, Error};
+
+    use crate::args::{parse_args, Command};
+
+    /// The days `run` goes through for `args`, with the part asked for.
+    fn run_days(args: &str) -> Vec<(usize, Option<u8>)> {
+        match parse_args(args.split_whitespace().map(String::from)) {
+            Ok(Command::Run {
+                selection, part, ..
+            }) => selection
+                .days_with(part)
+                .iter()
+                .map(|day| (day.number, part))
+                .collect(),
+            res => panic!("Not a run: {:?}", res),
+        }
+    }
+
+    #[test]
+    fn all_days_with_a_part() {
+        let days = run_days("run all --part 2");
+        assert_eq!(days.len(), 24);
+        assert_eq!(days[23], (24, Some(2)));
+
+        assert_eq!(run_days("run all").len(), 25);
+        assert_eq!(run_days("run all --part 1").len(), 25);
+    }
+
+    #[test]
+    fn missing_part() {
+        assert_eq!(run_days("run 25 --part 2"), [(25, Some(2))]);
+
+        let res = crate::DAYS[24].solve("1\n", Some(2), &Overrides::default());
+        assert_eq!(res, Err(Error::Usage("There is no part 2".to_string())));
+    }
+}
@@ -0,0 +1,228 @@
+//! Runs days on a pool of worker threads, each part on its own, with a
+//! table of their answers once they're all done.
+
+use std::{
+    any::Any,
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use advent::{solution::Value, Result};
+
+use crate::days::Day;
+
+/// One part of a day to solve.
+#[derive(Clone, Copy)]
+pub struct Task {
+    pub day: &'static Day,
+    pub part: u8,
+}
+
+/// Every part of the selected days, or only `part` if given.
+pub fn tasks(days: &'static [Day], part: Option<u8>) -> Vec<Task> {
+    days.iter()
+        .flat_map(|day| {
+            (1..=day.parts)
+                .filter(move |&p| part.is_none_or(|part| part == p))
+                .map(move |part| Task { day, part })
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    Solved(Value),
+    Error(String),
+    Panicked(String),
+}
+
+pub struct Outcome {
+    pub task: Task,
+    pub status: Status,
+    pub elapsed: Duration,
+}
+
+impl Outcome {
+    pub fn failed(&self) -> bool {
+        !matches!(self.status, Status::Solved(_))
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(msg) => msg.to_string(),
+        None => match payload.downcast_ref::<String>() {
+            Some(msg) => msg.clone(),
+            None => "Unknown panic".to_string(),
+        },
+    }
+}
+
+/// Solves the tasks with `solve` on `jobs` threads, each taking the next
+/// task as soon as it's done with one. A task that panics only fails
+/// itself. The outcomes are in the order of the tasks.
+pub fn run(
+    tasks: &[Task],
+    jobs: usize,
+    solve: impl Fn(Task) -> Result<Value> + Sync,
+) -> Vec<Outcome> {
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(tasks.len()) {
+            let (sender, next, solve) = (sender.clone(), &next, &solve);
+
+            scope.spawn(move || loop {
+                let idx = next.fetch_add(1, Ordering::Relaxed);
+                let Some(&task) = tasks.get(idx) else {
+                    break;
+                };
+
+                let start = Instant::now();
+                let status = match panic::catch_unwind(AssertUnwindSafe(|| solve(task))) {
+                    Ok(Ok(answer)) => Status::Solved(answer),
+                    Ok(Err(err)) => Status::Error(err.to_string()),
+                    Err(payload) => Status::Panicked(panic_message(&*payload)),
+                };
+
+                let outcome = Outcome {
+                    task,
+                    status,
+                    elapsed: start.elapsed(),
+                };
+                sender
+                    .send((idx, outcome))
+                    .expect("The receiver outlives the workers");
+            });
+        }
+    });
+    drop(sender);
+
+    let mut outcomes: Vec<(usize, Outcome)> = receiver.into_iter().collect();
+    outcomes.sort_by_key(|&(idx, _)| idx);
+    outcomes.into_iter().map(|(_, outcome)| outcome).collect()
+}
+
+/// The outcomes as a table, followed by the answers that are pictures.
+pub struct Table<'a>(pub &'a [Outcome]);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let answer = |outcome: &Outcome| match &outcome.status {
+            Status::Solved(Value::Grid(_)) => "(picture below)".to_string(),
+            Status::Solved(answer) => answer.to_string(),
+            Status::Error(_) | Status::Panicked(_) => "-".to_string(),
+        };
+        let width = self
+            .0
+            .iter()
+            .map(|o| answer(o).chars().count())
+            .chain(["Answer".len()])
+            .max()
+            .unwrap_or_default();
+
+        writeln!(
+            f,
+            "{:<4} {:<4} {:<width$} {:>12} Status",
+            "Day", "Part", "Answer", "Time"
+        )?;
+
+        for outcome in self.0 {
+            let time = format!("{:.2?}", outcome.elapsed);
+            write!(
+                f,
+                "{:02}   {:<4} {:<width$} {:>12} ",
+                outcome.task.day.number,
+                outcome.task.part,
+                answer(outcome),
+                time
+            )?;
+
+            match &outcome.status {
+                Status::Solved(_) => writeln!(f, "ok")?,
+                Status::Error(err) => writeln!(f, "FAIL: {}", err)?,
+                Status::Panicked(msg) => writeln!(f, "PANIC: {}", msg)?,
+            }
+        }
+
+        for outcome in self.0 {
+            if let Status::Solved(picture @ Value::Grid(_)) = &outcome.status {
+                write!(
+                    f,
+                    "\nDay {:02} part {}:\n{}\n",
+                    outcome.task.day.number, outcome.task.part, picture
+                )?;
+            }
+        }
+
+        let failed = self.0.iter().filter(|o| o.failed()).count();
+        writeln!(f, "\n{} parts, {} failed", self.0.len(), failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{invalid, solution::Value};
+
+    use super::{run, tasks, Status};
+    use crate::days::DAYS;
+
+    #[test]
+    fn every_part() {
+        let all: Vec<_> = tasks(&DAYS, None)
+            .iter()
+            .map(|task| (task.day.number, task.part))
+            .collect();
+        assert_eq!(all.len(), 49);
+        assert_eq!(all[..3], [(1, 1), (1, 2), (2, 1)]);
+        assert_eq!(all[48], (25, 1));
+
+        assert_eq!(tasks(&DAYS[..2], Some(2)).len(), 2);
+        assert!(tasks(&DAYS[24..], Some(2)).is_empty());
+    }
+
+    #[test]
+    fn isolated_failures() {
+        let outcomes = run(&tasks(&DAYS[..5], None), 3, |task| match task.day.number {
+            2 if task.part == 1 => panic!("Broken solver"),
+            3 => Err(invalid!("Broken input")),
+            day => Ok(Value::Number(day as i128 * 10 + task.part as i128)),
+        });
+
+        let statuses: Vec<_> = outcomes
+            .iter()
+            .map(|o| (o.task.day.number, o.task.part, &o.status))
+            .collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (1, 1, &Status::Solved(Value::Number(11))),
+                (1, 2, &Status::Solved(Value::Number(12))),
+                (2, 1, &Status::Panicked("Broken solver".to_string())),
+                (2, 2, &Status::Solved(Value::Number(22))),
+                (
+                    3,
+                    1,
+                    &Status::Error("Invalid puzzle: Broken input".to_string())
+                ),
+                (
+                    3,
+                    2,
+                    &Status::Error("Invalid puzzle: Broken input".to_string())
+                ),
+                (4, 1, &Status::Solved(Value::Number(41))),
+                (4, 2, &Status::Solved(Value::Number(42))),
+                (5, 1, &Status::Solved(Value::Number(51))),
+                (5, 2, &Status::Solved(Value::Number(52))),
+            ]
+        );
+        assert_eq!(outcomes.iter().filter(|o| o.failed()).count(), 3);
+    }
+}
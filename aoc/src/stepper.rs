@@ -0,0 +1,476 @@
+//! The terminal UI of `aoc step`, showing a day's simulation one state at a
+//! time, drawn by its `Display` impl.
+
+use std::{
+    io::{self, Write},
+    time::{Duration, Instant},
+};
+
+use advent::{
+    step::{self, Simulation},
+    Result,
+};
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute, queue,
+    style::Print,
+    terminal::{self, ClearType},
+};
+
+const KEYS: &str = "space run/pause  n step  g go to  r restart  arrows pan  f follow  \
+                    +/- speed  q quit";
+
+/// The delays between steps while running, from the fastest.
+const DELAYS_MS: [u64; 6] = [0, 10, 30, 100, 300, 1000];
+
+/// The rows and columns panned by the arrows.
+const PAN: (usize, usize) = (4, 8);
+
+/// How long a jump to a step runs before the keys are read again.
+const JUMP_SLICE: Duration = Duration::from_millis(50);
+
+/// The part of the displayed state that fits on the screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Viewport {
+    top: usize,
+    left: usize,
+    height: usize,
+    width: usize,
+}
+
+impl Viewport {
+    /// Scrolls just enough to keep `(row, col)` a quarter of the view away
+    /// from its edges.
+    fn follow(&mut self, (row, col): (usize, usize)) {
+        self.top = scroll(self.top, self.height, row);
+        self.left = scroll(self.left, self.width, col);
+    }
+}
+
+fn scroll(start: usize, len: usize, pos: usize) -> usize {
+    let margin = len / 4;
+    if pos < start + margin {
+        pos.saturating_sub(margin)
+    } else if pos + margin >= start + len {
+        pos + margin + 1 - len
+    } else {
+        start
+    }
+}
+
+/// Where the action is in `frame`: the marker if it has one, or else the
+/// first character that changed since `previous`.
+fn focus(frame: &str, previous: &str, marker: Option<char>) -> Option<(usize, usize)> {
+    let marked = marker.and_then(|marker| {
+        frame
+            .lines()
+            .enumerate()
+            .find_map(|(row, line)| line.chars().position(|c| c == marker).map(|col| (row, col)))
+    });
+    if marked.is_some() {
+        return marked;
+    }
+
+    let mut previous = previous.lines();
+    frame.lines().enumerate().find_map(|(row, line)| {
+        let before = previous.next().unwrap_or_default();
+        if line == before {
+            return None;
+        }
+
+        let col = line
+            .chars()
+            .zip(before.chars())
+            .position(|(now, then)| now != then)
+            .unwrap_or_else(|| line.chars().count().min(before.chars().count()));
+        Some((row, col))
+    })
+}
+
+/// The simulation, and how it's being watched.
+struct Stepper<'a> {
+    restart: &'a dyn Fn() -> Result<Simulation>,
+    simulation: Simulation,
+    title: String,
+    step: usize,
+    frame: String,
+    /// Set once the simulation is over, or failed.
+    finished: bool,
+    error: Option<String>,
+    running: bool,
+    delay: usize,
+    follow: bool,
+    view: Viewport,
+    /// The step being typed after `g`.
+    go_to: Option<String>,
+    /// The step being jumped to, a slice at a time.
+    target: Option<usize>,
+}
+
+impl Stepper<'_> {
+    fn advance(&mut self, steps: usize) {
+        self.take(steps);
+        self.refresh();
+    }
+
+    /// Takes up to `steps` steps without rendering them.
+    fn take(&mut self, steps: usize) {
+        let res = step::advance(&mut *self.simulation, steps);
+        match res {
+            Ok(taken) => {
+                self.step += taken;
+                self.finished |= taken < steps;
+            }
+            Err(err) => {
+                self.error = Some(err.to_string());
+                self.finished = true;
+            }
+        }
+
+        if self.finished {
+            self.running = false;
+            self.target = None;
+        }
+    }
+
+    /// Restarts if `step` is behind, then starts jumping ahead to it.
+    fn go_to(&mut self, step: usize) {
+        if step < self.step {
+            match (self.restart)() {
+                Ok(simulation) => self.simulation = simulation,
+                Err(err) => {
+                    self.error = Some(err.to_string());
+                    return;
+                }
+            }
+
+            self.step = 0;
+            self.finished = false;
+            self.error = None;
+        }
+
+        self.target = Some(step);
+        self.jump();
+    }
+
+    /// Steps towards the target for up to [`JUMP_SLICE`], so that a long
+    /// jump can be stopped in between.
+    fn jump(&mut self) {
+        let start = Instant::now();
+        while let Some(target) = self.target {
+            if self.step >= target {
+                self.target = None;
+            } else if start.elapsed() >= JUMP_SLICE {
+                break;
+            } else {
+                self.take(1);
+            }
+        }
+
+        self.refresh();
+    }
+
+    /// Renders the current state, moving the viewport along if it follows
+    /// the action.
+    fn refresh(&mut self) {
+        let frame = self.simulation.to_string();
+        if self.follow {
+            if let Some(focus) = focus(&frame, &self.frame, self.simulation.marker()) {
+                self.view.follow(focus);
+            }
+        }
+
+        self.frame = frame;
+    }
+
+    fn status(&self) -> String {
+        if let Some(step) = &self.go_to {
+            return format!("Go to step: {}_", step);
+        }
+        if let Some(target) = self.target {
+            return format!(
+                "Going to step {}, at {}  |  q or esc to stop",
+                target, self.step
+            );
+        }
+
+        let state = match (&self.error, self.finished, self.running) {
+            (Some(err), _, _) => format!("error: {}", err),
+            (None, true, _) => "finished".to_string(),
+            (None, false, true) => format!("running every {}ms", DELAYS_MS[self.delay]),
+            (None, false, false) => "paused".to_string(),
+        };
+        let follow = if self.follow { ", following" } else { "" };
+
+        format!(
+            "{}  step {}  {}{}  |  {}",
+            self.title, self.step, state, follow, KEYS
+        )
+    }
+
+    fn draw(&self, out: &mut impl Write) -> io::Result<()> {
+        let mut lines = self.frame.lines().skip(self.view.top);
+        for row in 0..self.view.height {
+            let line: String = lines
+                .next()
+                .unwrap_or_default()
+                .chars()
+                .skip(self.view.left)
+                .take(self.view.width)
+                .collect();
+
+            queue!(
+                out,
+                cursor::MoveTo(0, row as u16),
+                Print(line),
+                terminal::Clear(ClearType::UntilNewLine)
+            )?;
+        }
+
+        let status: String = self.status().chars().take(self.view.width).collect();
+        queue!(
+            out,
+            cursor::MoveTo(0, self.view.height as u16),
+            Print(status),
+            terminal::Clear(ClearType::UntilNewLine)
+        )?;
+        out.flush()
+    }
+
+    /// Handles a key press, returning `false` to quit.
+    fn key(&mut self, key: KeyEvent) -> bool {
+        if self.target.is_some() {
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => {
+                    self.target = None;
+                    self.refresh();
+                }
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    return false
+                }
+                _ => {}
+            }
+
+            return true;
+        }
+
+        if let Some(step) = &mut self.go_to {
+            match key.code {
+                KeyCode::Char(c @ '0'..='9') => step.push(c),
+                KeyCode::Backspace => _ = step.pop(),
+                KeyCode::Enter => {
+                    let step = step.parse().ok();
+                    self.go_to = None;
+                    if let Some(step) = step {
+                        self.go_to(step);
+                    }
+                }
+                KeyCode::Esc => self.go_to = None,
+                _ => {}
+            }
+
+            return true;
+        }
+
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return false,
+            KeyCode::Char(' ') => self.running = !self.running && !self.finished,
+            KeyCode::Char('n') => {
+                self.running = false;
+                self.advance(1);
+            }
+            KeyCode::Char('g') => {
+                self.running = false;
+                self.go_to = Some(String::new());
+            }
+            KeyCode::Char('r') => self.go_to(0),
+            KeyCode::Char('f') => {
+                self.follow = !self.follow;
+                self.refresh();
+            }
+            KeyCode::Char('+') => self.delay = self.delay.saturating_sub(1),
+            KeyCode::Char('-') => self.delay = (self.delay + 1).min(DELAYS_MS.len() - 1),
+            KeyCode::Up | KeyCode::Down | KeyCode::Left | KeyCode::Right => {
+                self.follow = false;
+                let view = &mut self.view;
+                match key.code {
+                    KeyCode::Up => view.top = view.top.saturating_sub(PAN.0),
+                    KeyCode::Down => view.top += PAN.0,
+                    KeyCode::Left => view.left = view.left.saturating_sub(PAN.1),
+                    _ => view.left += PAN.1,
+                }
+            }
+            _ => {}
+        }
+
+        true
+    }
+}
+
+/// The alternate screen in raw mode, left when dropped, even on errors.
+struct Screen;
+
+impl Screen {
+    fn enter() -> io::Result<Screen> {
+        terminal::enable_raw_mode()?;
+        let screen = Screen;
+        execute!(io::stdout(), terminal::EnterAlternateScreen, cursor::Hide)?;
+        Ok(screen)
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        _ = execute!(io::stdout(), cursor::Show, terminal::LeaveAlternateScreen);
+        _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Shows `simulation` until quit, rebuilding it with `restart` to go back.
+/// Returns the step it was left at.
+pub fn run(
+    title: String,
+    simulation: Simulation,
+    restart: &dyn Fn() -> Result<Simulation>,
+) -> io::Result<usize> {
+    let mut stepper = Stepper {
+        restart,
+        simulation,
+        title,
+        step: 0,
+        frame: String::new(),
+        finished: false,
+        error: None,
+        running: false,
+        delay: 3,
+        follow: true,
+        view: Viewport::default(),
+        go_to: None,
+        target: None,
+    };
+
+    let _screen = Screen::enter()?;
+    let mut out = io::stdout();
+    stepper.refresh();
+
+    loop {
+        let (width, height) = terminal::size()?;
+        stepper.view.width = width as usize;
+        stepper.view.height = (height as usize).saturating_sub(1);
+        stepper.draw(&mut out)?;
+
+        if stepper.target.is_some() {
+            if !event::poll(Duration::ZERO)? {
+                stepper.jump();
+                continue;
+            }
+        } else if stepper.running && !event::poll(Duration::from_millis(DELAYS_MS[stepper.delay]))?
+        {
+            stepper.advance(1);
+            continue;
+        }
+
+        if let Event::Key(key) = event::read()? {
+            if key.kind == KeyEventKind::Press && !stepper.key(key) {
+                return Ok(stepper.step);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fmt::Display, thread, time::Duration};
+
+    use advent::{
+        step::{Simulation, Steppable},
+        Result,
+    };
+    use crossterm::event::{KeyCode, KeyEvent};
+
+    use super::{focus, Stepper, Viewport};
+
+    /// Counts forever, slowly.
+    struct Counter(usize);
+
+    impl Display for Counter {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Steppable for Counter {
+        fn step(&mut self) -> Result<bool> {
+            thread::sleep(Duration::from_millis(1));
+            self.0 += 1;
+            Ok(true)
+        }
+    }
+
+    #[test]
+    fn following() {
+        let mut view = Viewport {
+            top: 0,
+            left: 0,
+            height: 20,
+            width: 40,
+        };
+
+        view.follow((10, 10));
+        assert_eq!((view.top, view.left), (0, 0));
+
+        view.follow((30, 50));
+        assert_eq!((view.top, view.left), (16, 21));
+
+        view.follow((18, 25));
+        assert_eq!((view.top, view.left), (13, 15));
+    }
+
+    #[test]
+    fn focusing() {
+        let before = "....\n.#..\n....";
+        let after = "....\n..#.\n....";
+
+        assert_eq!(focus(after, before, None), Some((1, 1)));
+        assert_eq!(focus(after, after, None), None);
+        assert_eq!(focus(after, before, Some('#')), Some((1, 2)));
+        assert_eq!(focus(after, before, Some('@')), Some((1, 1)));
+        assert_eq!(focus("..\n..\n.#", "..\n..", None), Some((2, 0)));
+        assert_eq!(focus("....", "..", None), Some((0, 2)));
+    }
+
+    #[test]
+    fn jumping() {
+        let restart = || -> Result<Simulation> { Ok(Box::new(Counter(0))) };
+        let mut stepper = Stepper {
+            restart: &restart,
+            simulation: restart().unwrap(),
+            title: String::new(),
+            step: 0,
+            frame: String::new(),
+            finished: false,
+            error: None,
+            running: false,
+            delay: 0,
+            follow: false,
+            view: Viewport::default(),
+            go_to: None,
+            target: None,
+        };
+
+        stepper.go_to(1_000_000);
+        assert_eq!(stepper.target, Some(1_000_000));
+        assert!(stepper.step > 0 && stepper.step < 1_000);
+        assert_eq!(stepper.frame, stepper.step.to_string());
+
+        let step = stepper.step;
+        assert!(stepper.key(KeyEvent::from(KeyCode::Esc)));
+        assert_eq!((stepper.target, stepper.step), (None, step));
+
+        stepper.go_to(3);
+        assert_eq!((stepper.target, stepper.step), (None, 3));
+        assert_eq!(stepper.frame, "3");
+    }
+}
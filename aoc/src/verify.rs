@@ -0,0 +1,202 @@
+//! Checks every day against the answers recorded in `answers.json`.
+//!
+//! The sample answers are the ones given in the puzzles' text, so they catch
+//! a wrong solver. Those of the bundled inputs were recorded from the
+//! solvers' output, so they only catch a solver that changed.
+
+use std::{collections::BTreeMap, fmt::Display};
+
+use advent::{input::Source, params::Overrides};
+use serde::Deserialize;
+
+use crate::days::Day;
+
+/// The inputs that are checked, as named in the answers file.
+pub const INPUTS: [(&str, Source); 2] = [("input", Source::Bundled), ("sample", Source::Sample)];
+
+/// The expected answers, by day (`day01`), input (`input` or `sample`) and
+/// part (`part1`).
+#[derive(Debug, Default, Deserialize)]
+pub struct Answers(BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>);
+
+impl Answers {
+    pub const BUNDLED: &'static str = include_str!("../answers.json");
+
+    pub fn parse(json: &str) -> Result<Answers, String> {
+        serde_json::from_str(json).map_err(|err| format!("Invalid answers: {}", err))
+    }
+
+    fn expected(&self, day: usize, input: &str) -> BTreeMap<u8, &str> {
+        let parts = self
+            .0
+            .get(&format!("day{:02}", day))
+            .and_then(|inputs| inputs.get(input));
+
+        parts
+            .into_iter()
+            .flatten()
+            .filter_map(|(part, answer)| {
+                let part = part.strip_prefix("part")?.parse().ok()?;
+                Some((part, answer.as_str()))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Fail {
+        expected: String,
+        got: String,
+    },
+    Error(String),
+    /// There's no recorded answer to compare to.
+    Unknown(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Check {
+    pub day: usize,
+    pub input: &'static str,
+    pub part: Option<u8>,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn failed(&self) -> bool {
+        matches!(self.outcome, Outcome::Fail { .. } | Outcome::Error(_))
+    }
+}
+
+/// Solves each input of the day, and compares the answers to the expected
+/// ones.
+pub fn verify(day: &Day, answers: &Answers) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for (input_name, source) in INPUTS.iter() {
+        let expected = answers.expected(day.number, input_name);
+        let check = |part, outcome| Check {
+            day: day.number,
+            input: input_name,
+            part,
+            outcome,
+        };
+
+        let solved = source
+            .read(day.bundled)
+            .and_then(|input| day.solve(&input, None, &Overrides::default()));
+
+        match solved {
+            Ok(solved) => checks.extend(solved.into_iter().map(|(part, got)| {
+                let got = got.to_string();
+                let outcome = match expected.get(&part) {
+                    Some(&expected) if expected == got => Outcome::Pass,
+                    Some(&expected) => Outcome::Fail {
+                        expected: expected.to_string(),
+                        got,
+                    },
+                    None => Outcome::Unknown(got),
+                };
+
+                check(Some(part), outcome)
+            })),
+            Err(err) if expected.is_empty() => {
+                checks.push(check(None, Outcome::Error(err.to_string())));
+            }
+            Err(err) => checks.extend(
+                expected
+                    .keys()
+                    .map(|&part| check(Some(part), Outcome::Error(err.to_string()))),
+            ),
+        }
+    }
+
+    checks
+}
+
+/// Multi-line answers, like pictures, are shown on one line.
+fn one_line(answer: &str) -> String {
+    answer.replace('\n', "\\n")
+}
+
+pub struct Table<'a>(pub &'a [Check]);
+
+impl Display for Table<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<4} {:<7} {:<5} Result", "Day", "Input", "Part")?;
+
+        for check in self.0 {
+            let part = check
+                .part
+                .map_or_else(|| "-".to_string(), |p| p.to_string());
+            write!(f, "{:02}   {:<7} {:<5} ", check.day, check.input, part)?;
+
+            match &check.outcome {
+                Outcome::Pass => writeln!(f, "ok")?,
+                Outcome::Fail { expected, got } => writeln!(
+                    f,
+                    "FAIL: expected {}, got {}",
+                    one_line(expected),
+                    one_line(got)
+                )?,
+                Outcome::Error(err) => writeln!(f, "FAIL: {}", err)?,
+                Outcome::Unknown(got) => writeln!(f, "no recorded answer, got {}", one_line(got))?,
+            }
+        }
+
+        let failed = self.0.iter().filter(|c| c.failed()).count();
+        writeln!(f, "\n{} checks, {} failed", self.0.len(), failed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{verify, Answers, Outcome};
+    use crate::days::DAYS;
+
+    #[test]
+    fn parse_answers() {
+        let answers = Answers::parse(
+            r#"{ "day01": { "input": { "part1": "1", "part2": "2" }, "sample": {} } }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            answers.expected(1, "input").into_iter().collect::<Vec<_>>(),
+            vec![(1, "1"), (2, "2")]
+        );
+        assert!(answers.expected(1, "sample").is_empty());
+        assert!(answers.expected(2, "input").is_empty());
+        assert!(Answers::parse("[]").is_err());
+    }
+
+    #[test]
+    fn outcomes() {
+        let answers =
+            Answers::parse(r#"{ "day01": { "sample": { "part1": "24000", "part2": "3" } } }"#)
+                .unwrap();
+
+        let outcomes: Vec<_> = verify(&DAYS[0], &answers)
+            .into_iter()
+            .filter(|c| c.input == "sample")
+            .map(|c| c.outcome)
+            .collect();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                Outcome::Pass,
+                Outcome::Fail {
+                    expected: "3".to_string(),
+                    got: "45000".to_string()
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn bundled_answers_parse() {
+        Answers::parse(Answers::BUNDLED).unwrap();
+    }
+}
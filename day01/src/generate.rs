@@ -0,0 +1,26 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+/// The calories carried by each elf.
+pub fn elves(rng: &mut Rng) -> Vec<Vec<i64>> {
+    (0..rng.range(1..=12))
+        .map(|_| {
+            (0..rng.range(1..=6))
+                .map(|_| rng.range(1000..=70000))
+                .collect()
+        })
+        .collect()
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    let blocks: Vec<String> = elves(rng)
+        .iter()
+        .map(|elf| {
+            let lines: Vec<_> = elf.iter().map(i64::to_string).collect();
+            lines.join("\n")
+        })
+        .collect();
+
+    blocks.join("\n\n") + "\n"
+}
@@ -0,0 +1,77 @@
+use advent::{
+    parsers::{parse_all, parse_blocks, parse_unsigned},
+    solution::Solution,
+    Error, Result,
+};
+use nom::{character::complete::line_ending, multi::separated_list1};
+
+pub mod generate;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// The calories carried by each elf.
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        let calories = separated_list1(line_ending, parse_unsigned::<i64>);
+        let elves = parse_all(parse_blocks(calories), input)?;
+
+        Ok(elves.into_iter().map(|elf| elf.into_iter().sum()).collect())
+    }
+
+    fn part1(elves: &Vec<i64>) -> Result<i64> {
+        elves.iter().copied().max().ok_or(Error::NoSolution)
+    }
+
+    fn part2(elves: &Vec<i64>) -> Result<i64> {
+        let mut elves = elves.clone();
+        elves.sort();
+        elves.reverse();
+
+        Ok(elves.into_iter().take(3).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day01};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Day01::parse(INPUT).and_then(|i| Day01::part1(&i)),
+            Ok(24000)
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day01::parse(INPUT).and_then(|i| Day01::part2(&i)),
+            Ok(45000)
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day01>(CASES, generate::input);
+    }
+
+    #[test]
+    fn top_three_hold_the_most() {
+        random::check(CASES, |rng| {
+            let elves = Day01::parse(&generate::input(rng)).unwrap();
+            assert!(Day01::part1(&elves).unwrap() <= Day01::part2(&elves).unwrap());
+        });
+    }
+}
@@ -0,0 +1,15 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+pub fn input(rng: &mut Rng) -> String {
+    (0..rng.range(1..=50))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['A', 'B', 'C']),
+                rng.choose(&['X', 'Y', 'Z'])
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,79 @@
+use advent::{parsers::parse_lines, solution::Solution, Result};
+use nom::{
+    character::complete::{char, one_of},
+    combinator::map,
+    sequence::separated_pair,
+    IResult,
+};
+
+pub mod generate;
+
+fn player(input: &str) -> IResult<&str, i64> {
+    map(one_of("XYZ"), |c: char| (c as u8 - b'X') as i64)(input)
+}
+
+fn opponent(input: &str) -> IResult<&str, i64> {
+    map(one_of("ABC"), |c: char| (c as u8 - b'A') as i64)(input)
+}
+
+/// The score of a round, given both shapes.
+pub fn calc_score((opponent, player): (i64, i64)) -> i64 {
+    player + 1 + (player - opponent + 1).rem_euclid(3) * 3
+}
+
+/// The shapes played when the second column is the outcome of the round:
+/// 0 to lose, 1 to draw and 2 to win.
+pub fn make_play((opponent, res): (i64, i64)) -> (i64, i64) {
+    (opponent, (opponent - 1 + res).rem_euclid(3))
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// The opponent's shape and the second column, both from 0 to 2.
+    type Input = Vec<(i64, i64)>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<(i64, i64)>> {
+        Ok(parse_lines(
+            separated_pair(opponent, char(' '), player),
+            input,
+        )?)
+    }
+
+    fn part1(rounds: &Vec<(i64, i64)>) -> Result<i64> {
+        Ok(rounds.iter().copied().map(calc_score).sum())
+    }
+
+    fn part2(rounds: &Vec<(i64, i64)>) -> Result<i64> {
+        Ok(rounds.iter().copied().map(make_play).map(calc_score).sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day02};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day02::parse(INPUT).and_then(|i| Day02::part1(&i)), Ok(15));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day02::parse(INPUT).and_then(|i| Day02::part2(&i)), Ok(12));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day02>(CASES, generate::input);
+    }
+}
@@ -0,0 +1,44 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// A group of three rucksacks sharing only their badge, each with a single
+/// item in both of its compartments.
+fn group(rng: &mut Rng) -> [String; 3] {
+    let items: Vec<char> = ITEMS.chars().collect();
+    let badge = *rng.choose(&items);
+
+    // Every other item belongs to a single rucksack, so that they share
+    // nothing else.
+    let mut others: Vec<char> = items.into_iter().filter(|&c| c != badge).collect();
+    rng.shuffle(&mut others);
+    let mut pools = others.chunks(others.len() / 3);
+
+    [(); 3].map(|_| {
+        let pool = pools.next().expect("There are three pools");
+        let len = rng.range(2..=pool.len().div_ceil(2));
+        let (shared, rest) = pool.split_first().expect("Pools aren't empty");
+
+        let mut first: Vec<char> = [*shared, badge]
+            .into_iter()
+            .chain(rest[..len - 2].iter().copied())
+            .collect();
+        let mut second: Vec<char> = [*shared]
+            .into_iter()
+            .chain(rest[len - 2..2 * len - 3].iter().copied())
+            .collect();
+        rng.shuffle(&mut first);
+        rng.shuffle(&mut second);
+
+        first.into_iter().chain(second).collect()
+    })
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    (0..rng.range(1..=8))
+        .flat_map(|_| group(rng))
+        .map(|rucksack| rucksack + "\n")
+        .collect()
+}
@@ -0,0 +1,93 @@
+use std::collections::HashSet;
+
+use advent::{invalid, solution::Solution, Result};
+
+pub mod generate;
+
+fn items(rucksack: &str) -> HashSet<u8> {
+    rucksack.bytes().collect()
+}
+
+/// The only item found in every one of `groups`.
+pub fn common_item(groups: &[&str]) -> Result<u8> {
+    let common = groups
+        .iter()
+        .map(|g| items(g))
+        .reduce(|a, b| a.intersection(&b).copied().collect())
+        .unwrap_or_default();
+
+    match common.into_iter().collect::<Vec<_>>()[..] {
+        [item] => Ok(item),
+        _ => Err(invalid!("{:?} don't share exactly one item", groups)),
+    }
+}
+
+pub fn priority(ch: u8) -> i64 {
+    (if ch.is_ascii_lowercase() {
+        ch - b'a' + 1
+    } else {
+        ch - b'A' + 27
+    }) as i64
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// One rucksack per line.
+    type Input = Vec<String>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(rucksacks: &Vec<String>) -> Result<i64> {
+        rucksacks
+            .iter()
+            .map(|r| {
+                let (a, b) = r.split_at(r.len() / 2);
+                common_item(&[a, b])
+            })
+            .map(|item| item.map(priority))
+            .sum()
+    }
+
+    fn part2(rucksacks: &Vec<String>) -> Result<i64> {
+        rucksacks
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .chunks(3)
+            .map(common_item)
+            .map(|item| item.map(priority))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day03};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day03::parse(INPUT).and_then(|i| Day03::part1(&i)), Ok(157));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day03::parse(INPUT).and_then(|i| Day03::part2(&i)), Ok(70));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day03>(CASES, generate::input);
+    }
+}
@@ -0,0 +1,17 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+fn range(rng: &mut Rng) -> (u32, u32) {
+    let start = rng.range(1..=99);
+    (start, rng.range(start..=99))
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    (0..rng.range(1..=50))
+        .map(|_| {
+            let (a, b) = (range(rng), range(rng));
+            format!("{}-{},{}-{}\n", a.0, a.1, b.0, b.1)
+        })
+        .collect()
+}
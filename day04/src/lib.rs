@@ -0,0 +1,80 @@
+use advent::{
+    intervals::Interval,
+    parsers::{parse_lines, parse_unsigned},
+    solution::Solution,
+    Result,
+};
+use nom::{character::complete::char, combinator::map, sequence::separated_pair, IResult};
+
+pub mod generate;
+
+fn parse_range(input: &str) -> IResult<&str, Interval> {
+    map(
+        separated_pair(parse_unsigned, char('-'), parse_unsigned),
+        |(start, end)| Interval::new(start, end),
+    )(input)
+}
+
+/// Parses the sections of a pair of elves, like `2-4,6-8`.
+pub fn parse_line(input: &str) -> IResult<&str, (Interval, Interval)> {
+    separated_pair(parse_range, char(','), parse_range)(input)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// The sections assigned to each pair of elves.
+    type Input = Vec<(Interval, Interval)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Interval, Interval)>> {
+        Ok(parse_lines(parse_line, input)?)
+    }
+
+    fn part1(pairs: &Vec<(Interval, Interval)>) -> Result<usize> {
+        Ok(pairs
+            .iter()
+            .filter(|(a, b)| a.contains_interval(b) || b.contains_interval(a))
+            .count())
+    }
+
+    fn part2(pairs: &Vec<(Interval, Interval)>) -> Result<usize> {
+        Ok(pairs.iter().filter(|(a, b)| a.overlaps(b)).count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day04};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day04::parse(INPUT).and_then(|i| Day04::part1(&i)), Ok(2));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day04::parse(INPUT).and_then(|i| Day04::part2(&i)), Ok(4));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day04>(CASES, generate::input);
+    }
+
+    #[test]
+    fn containing_pairs_overlap() {
+        random::check(CASES, |rng| {
+            let pairs = Day04::parse(&generate::input(rng)).unwrap();
+            assert!(Day04::part1(&pairs).unwrap() <= Day04::part2(&pairs).unwrap());
+        });
+    }
+}
@@ -0,0 +1,72 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+use crate::{Instruction, Stacks};
+
+const CRATES: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Between two and nine stacks, none of them empty, with more crates than
+/// stacks.
+pub fn stacks(rng: &mut Rng) -> Stacks {
+    let crates: Vec<char> = CRATES.chars().collect();
+    (0..rng.range(2..=9))
+        .map(|i| {
+            let min = if i == 0 { 2 } else { 1 };
+            (0..rng.range(min..=6))
+                .map(|_| *rng.choose(&crates))
+                .collect()
+        })
+        .collect()
+}
+
+/// Moves that leave at least one crate on every stack. Since there are more
+/// crates than stacks, some stack always has one to spare.
+pub fn instructions(rng: &mut Rng, stacks: &Stacks) -> Vec<Instruction> {
+    let mut sizes: Vec<usize> = stacks.iter().map(|s| s.len()).collect();
+
+    (0..rng.range(1..=30))
+        .map(|_| {
+            let spare: Vec<usize> = (0..sizes.len()).filter(|&i| sizes[i] > 1).collect();
+            let from = *rng.choose(&spare);
+            let to = (from + rng.range(1..=sizes.len() - 1)) % sizes.len();
+
+            let count = rng.range(1..=sizes[from] - 1);
+            sizes[from] -= count;
+            sizes[to] += count;
+            Instruction { count, from, to }
+        })
+        .collect()
+}
+
+/// The stacks drawn bottom up, their numbers, and the moves.
+pub fn input(rng: &mut Rng) -> String {
+    let stacks = stacks(rng);
+    let height = stacks.iter().map(|s| s.len()).max().unwrap_or(0);
+
+    let mut input = String::new();
+    for y in (0..height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(y) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        input += &(row.join(" ") + "\n");
+    }
+
+    let numbers: Vec<String> = (1..=stacks.len()).map(|i| format!(" {} ", i)).collect();
+    input += &(numbers.join(" ") + "\n\n");
+
+    for ins in instructions(rng, &stacks) {
+        input += &format!(
+            "move {} from {} to {}\n",
+            ins.count,
+            ins.from + 1,
+            ins.to + 1
+        );
+    }
+
+    input
+}
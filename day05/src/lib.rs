@@ -0,0 +1,297 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use advent::{
+    ensure, invalid,
+    parsers::{parse_all, parse_unsigned},
+    solution::Solution,
+    step::{Simulation, Steppable},
+    Error, Result,
+};
+#[allow(unused_imports)]
+use dbg_pls::pretty;
+use nom::{
+    bytes::complete::{tag, take_until},
+    character::complete::{line_ending, multispace1},
+    combinator::map_opt,
+    multi::separated_list1,
+    sequence::{pair, preceded, tuple},
+    IResult,
+};
+
+pub mod generate;
+
+/// The crates of each stack, from the bottom up.
+pub type Stacks = Vec<VecDeque<char>>;
+
+fn stacks(input: &str) -> Result<Stacks> {
+    let lines = input.lines().collect::<Vec<_>>();
+    let split_idx = lines
+        .iter()
+        .position(|l| l.is_empty())
+        .ok_or_else(|| invalid!("No empty line after the stacks"))?;
+
+    // The last line before the empty one only numbers the stacks.
+    let stack_lines = &lines[..split_idx.saturating_sub(1)];
+    let stacks_count = stack_lines.last().map_or(0, |l| (l.len() + 1) / 4);
+    let mut stacks = vec![VecDeque::<char>::new(); stacks_count];
+
+    for line in stack_lines.iter().rev() {
+        let chars = line.chars().collect::<Vec<_>>();
+        for (stack_i, ch) in chars.into_iter().skip(1).step_by(4).enumerate() {
+            if ch != ' ' {
+                stacks
+                    .get_mut(stack_i)
+                    .ok_or_else(|| invalid!("Crate line wider than the stacks: {:?}", line))?
+                    .push_back(ch)
+            };
+        }
+    }
+
+    Ok(stacks)
+}
+
+/// Moves `count` crates between stacks, numbered from 0.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+fn parse_instruction(input: &str) -> IResult<&str, Instruction> {
+    let num = parse_unsigned::<usize>;
+
+    map_opt(
+        tuple((
+            preceded(tag("move "), num),
+            preceded(tag(" from "), num),
+            preceded(tag(" to "), num),
+        )),
+        |(count, from, to)| {
+            Some(Instruction {
+                count,
+                from: usize::checked_sub(from, 1)?,
+                to: usize::checked_sub(to, 1)?,
+            })
+        },
+    )(input)
+}
+
+fn instructions(input: &str) -> Result<Vec<Instruction>> {
+    let stacks = pair(take_until("\n\n"), multispace1);
+    let instructions = separated_list1(line_ending, parse_instruction);
+
+    Ok(parse_all(preceded(stacks, instructions), input)?)
+}
+
+pub struct Crates {
+    pub stacks: Stacks,
+    pub instructions: Vec<Instruction>,
+}
+
+/// How a crane moves the crates of an instruction.
+pub type Apply = fn(&mut Stacks, &Instruction);
+
+/// The CrateMover 9000 of part 1 moves the crates one at a time.
+fn move_one_by_one(stacks: &mut Stacks, ins: &Instruction) {
+    for _ in 0..ins.count {
+        let c = stacks[ins.from].pop_back().unwrap();
+        stacks[ins.to].push_back(c);
+    }
+}
+
+/// The CrateMover 9001 of part 2 moves them all at once.
+fn move_all_at_once(stacks: &mut Stacks, ins: &Instruction) {
+    let split_idx = stacks[ins.from].len() - ins.count;
+    let mut taking = stacks[ins.from].split_off(split_idx);
+    stacks[ins.to].append(&mut taking);
+}
+
+/// The stacks being rearranged by a crane, one instruction at a time.
+pub struct Crane {
+    stacks: Stacks,
+    instructions: Vec<Instruction>,
+    /// The number of instructions applied so far.
+    applied: usize,
+    apply: Apply,
+}
+
+impl Crane {
+    pub fn new(crates: &Crates, apply: Apply) -> Crane {
+        Crane {
+            stacks: crates.stacks.clone(),
+            instructions: crates.instructions.clone(),
+            applied: 0,
+            apply,
+        }
+    }
+
+    /// Applies the next instruction, once checked that it can be. Returns
+    /// `false` once they all were.
+    pub fn apply_next(&mut self) -> Result<bool> {
+        let Some(ins) = self.instructions.get(self.applied) else {
+            return Ok(false);
+        };
+
+        ensure!(
+            ins.from < self.stacks.len() && ins.to < self.stacks.len(),
+            "There are only {} stacks",
+            self.stacks.len()
+        );
+        ensure!(
+            self.stacks[ins.from].len() >= ins.count,
+            "Can't move {} crates from stack {}",
+            ins.count,
+            ins.from + 1
+        );
+
+        (self.apply)(&mut self.stacks, ins);
+        self.applied += 1;
+        Ok(true)
+    }
+
+    /// The crates on top of the stacks.
+    pub fn top(&self) -> Result<String> {
+        self.stacks
+            .iter()
+            .map(|s| s.back().copied().ok_or(Error::NoSolution))
+            .collect()
+    }
+}
+
+/// The stacks drawn as in the input, followed by the last instruction.
+impl Display for Crane {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let height = self.stacks.iter().map(VecDeque::len).max().unwrap_or(0);
+        for level in (0..height).rev() {
+            let row: Vec<String> = self
+                .stacks
+                .iter()
+                .map(|stack| match stack.get(level) {
+                    Some(c) => format!("[{}]", c),
+                    None => "   ".to_string(),
+                })
+                .collect();
+            writeln!(f, "{}", row.join(" ").trim_end())?;
+        }
+
+        let numbers: Vec<String> = (1..=self.stacks.len())
+            .map(|i| format!(" {} ", i))
+            .collect();
+        writeln!(f, "{}", numbers.join(" ").trim_end())?;
+
+        if let Some(ins) = self.applied.checked_sub(1).map(|i| self.instructions[i]) {
+            writeln!(
+                f,
+                "\nmove {} from {} to {}",
+                ins.count,
+                ins.from + 1,
+                ins.to + 1
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Steppable for Crane {
+    fn step(&mut self) -> Result<bool> {
+        self.apply_next()
+    }
+}
+
+/// Moves the crates with `apply`, and returns the crates on top of the
+/// stacks.
+pub fn run(crates: &Crates, apply: Apply) -> Result<String> {
+    let mut crane = Crane::new(crates, apply);
+    while crane.apply_next()? {}
+
+    crane.top()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Crates;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Crates> {
+        Ok(Crates {
+            stacks: stacks(input)?,
+            instructions: instructions(input)?,
+        })
+    }
+
+    fn part1(crates: &Crates) -> Result<String> {
+        run(crates, move_one_by_one)
+    }
+
+    fn part2(crates: &Crates) -> Result<String> {
+        run(crates, move_all_at_once)
+    }
+
+    fn simulate(crates: &Crates, part: u8) -> Option<Simulation> {
+        let apply = match part {
+            1 => move_one_by_one,
+            _ => move_all_at_once,
+        };
+
+        Some(Box::new(Crane::new(crates, apply)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+        step,
+    };
+
+    use super::{generate, Day05};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Day05::parse(INPUT)
+                .and_then(|i| Day05::part1(&i))
+                .as_deref(),
+            Ok("CMZ")
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day05::parse(INPUT)
+                .and_then(|i| Day05::part2(&i))
+                .as_deref(),
+            Ok("MCD")
+        );
+    }
+
+    #[test]
+    fn ragged_crates() {
+        assert!(Day05::parse("[D]     [Q]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\n").is_err());
+    }
+
+    #[test]
+    fn simulation() {
+        let crates = Day05::parse(INPUT).unwrap();
+        let mut crane = Day05::simulate(&crates, 2).unwrap();
+
+        assert_eq!(step::advance(&mut *crane, 10), Ok(4));
+        assert_eq!(
+            crane.to_string(),
+            "        [D]\n        [N]\n        [Z]\n[M] [C] [P]\n 1   2   3\n\nmove 1 from 1 to 2\n"
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day05>(CASES, generate::input);
+    }
+}
@@ -0,0 +1,17 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+const LETTERS: &str = "abcdefghijklmnopqrstuvwxyz";
+
+/// Letters from a few repeated ones, ending with fourteen different ones so
+/// that both markers are found.
+pub fn input(rng: &mut Rng) -> String {
+    let letters: Vec<char> = LETTERS.chars().collect();
+    let few = rng.sample(&letters, 3);
+
+    let noise: String = (0..rng.range(0..=40)).map(|_| *rng.choose(&few)).collect();
+    let marker: String = rng.sample(&letters, 14).into_iter().collect();
+
+    noise + &marker + "\n"
+}
@@ -0,0 +1,104 @@
+use std::collections::BTreeSet;
+
+use advent::{solution::Solution, Error, Result};
+
+pub mod generate;
+
+/// The number of characters read once the last `len` ones are all different.
+pub fn find_marker(input: &str, len: usize) -> Result<usize> {
+    let chars = input.chars().collect::<Vec<_>>();
+
+    chars
+        .windows(len)
+        .position(|window| window.iter().collect::<BTreeSet<_>>().len() == len)
+        .map(|i| i + len)
+        .ok_or(Error::NoSolution)
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// The datastream buffer.
+    type Input = String;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<String> {
+        Ok(input.trim_end().to_string())
+    }
+
+    fn part1(input: &String) -> Result<usize> {
+        find_marker(input, 4)
+    }
+
+    fn part2(input: &String) -> Result<usize> {
+        find_marker(input, 14)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day06};
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Day06::part1(&"bvwbjplbgvbhsrlpgdmjqwftvncz".to_string()),
+            Ok(5)
+        );
+        assert_eq!(
+            Day06::part1(&"nppdvjthqldpwncqszvftbrmjlhg".to_string()),
+            Ok(6)
+        );
+        assert_eq!(
+            Day06::part1(&"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string()),
+            Ok(10)
+        );
+        assert_eq!(
+            Day06::part1(&"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string()),
+            Ok(11)
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day06::part2(&"mjqjpqmgbljsphdztnvjfqwrcgsmlb".to_string()),
+            Ok(19)
+        );
+        assert_eq!(
+            Day06::part2(&"bvwbjplbgvbhsrlpgdmjqwftvncz".to_string()),
+            Ok(23)
+        );
+        assert_eq!(
+            Day06::part2(&"nppdvjthqldpwncqszvftbrmjlhg".to_string()),
+            Ok(23)
+        );
+        assert_eq!(
+            Day06::part2(&"nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".to_string()),
+            Ok(29)
+        );
+        assert_eq!(
+            Day06::part2(&"zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".to_string()),
+            Ok(26)
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day06>(CASES, generate::input);
+    }
+
+    #[test]
+    fn packet_marker_comes_first() {
+        random::check(CASES, |rng| {
+            let signal = Day06::parse(&generate::input(rng)).unwrap();
+            assert!(Day06::part1(&signal).unwrap() + 10 <= Day06::part2(&signal).unwrap());
+        });
+    }
+}
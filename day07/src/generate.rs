@@ -0,0 +1,85 @@
+//! Random puzzle inputs.
+
+use std::collections::BTreeMap;
+
+use advent::random::Rng;
+
+/// A directory, with the size of each file in it.
+#[derive(Clone, Debug, Default)]
+pub struct Dir {
+    pub dirs: BTreeMap<String, Dir>,
+    pub files: BTreeMap<String, usize>,
+}
+
+impl Dir {
+    /// The size of every file in the directory and below.
+    pub fn size(&self) -> usize {
+        self.files.values().sum::<usize>() + self.dirs.values().map(Dir::size).sum::<usize>()
+    }
+}
+
+fn name(rng: &mut Rng) -> String {
+    (0..rng.range(1..=4))
+        .map(|_| rng.range(b'a'..=b'z') as char)
+        .collect()
+}
+
+pub fn dir(rng: &mut Rng, depth: usize) -> Dir {
+    let mut dir = Dir::default();
+
+    for _ in 0..rng.range(0..=4) {
+        let size = rng.range(1..=300000);
+        dir.files.insert(name(rng) + "." + &name(rng), size);
+    }
+
+    if depth > 0 {
+        for _ in 0..rng.range(0..=3) {
+            let name = name(rng);
+            if !dir.files.contains_key(&name) {
+                dir.dirs.insert(name, self::dir(rng, depth - 1));
+            }
+        }
+    }
+
+    dir
+}
+
+/// Lists `dir` and then each of its directories, in a random order.
+fn browse(rng: &mut Rng, dir: &Dir, out: &mut Vec<String>) {
+    let mut entries: Vec<String> = dir
+        .dirs
+        .keys()
+        .map(|name| format!("dir {}", name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(name, size)| format!("{} {}", size, name)),
+        )
+        .collect();
+    rng.shuffle(&mut entries);
+
+    out.push("$ ls".to_string());
+    out.extend(entries);
+
+    let mut names: Vec<&String> = dir.dirs.keys().collect();
+    rng.shuffle(&mut names);
+    for name in names {
+        out.push(format!("$ cd {}", name));
+        browse(rng, &dir.dirs[name], out);
+        out.push("$ cd ..".to_string());
+    }
+}
+
+/// The terminal output of browsing `root`.
+pub fn terminal(rng: &mut Rng, root: &Dir) -> String {
+    let mut lines = vec!["$ cd /".to_string()];
+    browse(rng, root, &mut lines);
+
+    lines.join("\n") + "\n"
+}
+
+/// The terminal output of browsing a random file system.
+pub fn input(rng: &mut Rng) -> String {
+    let root = dir(rng, 3);
+    terminal(rng, &root)
+}
@@ -0,0 +1,288 @@
+use std::collections::BTreeMap;
+
+use advent::{
+    invalid,
+    params::{Overrides, WithParams},
+    parsers::{parse_lines, parse_unsigned},
+    solution::Solution,
+    Error, Result,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{char, not_line_ending},
+    combinator::{map, value},
+    sequence::{preceded, separated_pair},
+    IResult,
+};
+
+pub mod generate;
+
+type INode = usize;
+
+type DirChildren = BTreeMap<String, INode>;
+
+#[derive(variantly::Variantly)]
+enum FileKind {
+    Regular,
+    Dir(DirChildren),
+}
+
+struct File {
+    parent: Option<INode>,
+    size: usize,
+    kind: FileKind,
+}
+
+pub struct Fs {
+    files: Vec<File>,
+    curr_file: INode,
+}
+
+const ROOT_INODE: INode = 0;
+
+impl Fs {
+    pub fn new() -> Fs {
+        Fs {
+            files: vec![File {
+                parent: None,
+                size: 0,
+                kind: FileKind::Dir(DirChildren::new()),
+            }],
+            curr_file: ROOT_INODE,
+        }
+    }
+
+    fn get_cwd_children(&self) -> &DirChildren {
+        self.files[self.curr_file].kind.dir_ref().unwrap()
+    }
+
+    fn get_cwd_children_mut(&mut self) -> &mut DirChildren {
+        self.files[self.curr_file].kind.dir_mut().unwrap()
+    }
+
+    pub fn cd(&mut self, to_dir: &str) -> Result<()> {
+        let inode = match to_dir {
+            "/" => Some(ROOT_INODE),
+            ".." => self.files[self.curr_file].parent,
+            name => self.get_cwd_children().get(name).copied(),
+        };
+
+        self.curr_file = inode
+            .filter(|&inode| self.files[inode].kind.is_dir())
+            .ok_or_else(|| invalid!("No directory '{}' to cd into", to_dir))?;
+        Ok(())
+    }
+
+    pub fn mkdir(&mut self, dir_name: String) -> Result<()> {
+        let id = self.files.len();
+        self.files.push(File {
+            parent: Some(self.curr_file),
+            size: 0,
+            kind: FileKind::Dir(DirChildren::new()),
+        });
+
+        self.insert(dir_name, id)
+    }
+
+    pub fn touch(&mut self, file_name: String, size: usize) -> Result<()> {
+        let id = self.files.len();
+        self.files.push(File {
+            parent: Some(self.curr_file),
+            size,
+            kind: FileKind::Regular,
+        });
+
+        self.insert(file_name, id)
+    }
+
+    fn insert(&mut self, name: String, id: INode) -> Result<()> {
+        match self.get_cwd_children_mut().insert(name, id) {
+            None => Ok(()),
+            Some(_) => Err(invalid!("A file is listed twice")),
+        }
+    }
+
+    /// The total size of the files, once [`Fs::recalculate_sizes`] was called.
+    pub fn used(&self) -> usize {
+        self.files[ROOT_INODE].size
+    }
+
+    /// The size of every directory, the root included.
+    pub fn dir_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.files
+            .iter()
+            .filter(|f| f.kind.is_dir())
+            .map(|f| f.size)
+    }
+
+    pub fn recalculate_sizes(&mut self) {
+        self.recalculate_sizes_rec(ROOT_INODE);
+    }
+
+    fn recalculate_sizes_rec(&mut self, inode: usize) -> usize {
+        match &self.files[inode].kind {
+            FileKind::Regular => self.files[inode].size,
+            FileKind::Dir(children) => {
+                let child_ids = children.values().copied().collect::<Vec<_>>();
+                let size: usize = child_ids
+                    .into_iter()
+                    .map(|id| self.recalculate_sizes_rec(id))
+                    .sum();
+
+                self.files[inode].size = size;
+                size
+            }
+        }
+    }
+}
+
+impl Default for Fs {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+enum Line<'a> {
+    Cd(&'a str),
+    Ls,
+    Dir(&'a str),
+    File(usize, &'a str),
+}
+
+fn parse_line(input: &str) -> IResult<&str, Line<'_>> {
+    alt((
+        map(preceded(tag("$ cd "), not_line_ending), Line::Cd),
+        value(Line::Ls, tag("$ ls")),
+        map(preceded(tag("dir "), not_line_ending), Line::Dir),
+        map(
+            separated_pair(parse_unsigned, char(' '), not_line_ending),
+            |(size, name)| Line::File(size, name),
+        ),
+    ))(input)
+}
+
+/// Replays the terminal output to find every file and the directories' sizes.
+pub fn build_fs(input: &str) -> Result<Fs> {
+    let mut lines = parse_lines(parse_line, input)?.into_iter().peekable();
+    let mut fs = Fs::new();
+
+    while let Some(line) = lines.next() {
+        match line {
+            Line::Cd(dir) => fs.cd(dir)?,
+
+            Line::Ls => loop {
+                match lines.peek() {
+                    Some(Line::Dir(name)) => fs.mkdir(name.to_string())?,
+                    Some(&Line::File(size, name)) => fs.touch(name.to_string(), size)?,
+                    _ => break,
+                }
+                lines.next();
+            },
+
+            Line::Dir(_) | Line::File(..) => return Err(invalid!("Listing outside of ls")),
+        }
+    }
+
+    fs.recalculate_sizes();
+    Ok(fs)
+}
+
+pub struct Day07;
+
+advent::params! {
+    pub struct Params in "day07" {
+        /// The size of the disk.
+        capacity: usize = 70000000,
+        /// The free space the update needs.
+        update_needed: usize = 30000000,
+    }
+}
+
+impl Solution for Day07 {
+    type Input = WithParams<Fs, Params>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        Ok(WithParams {
+            input: build_fs(input)?,
+            params: overrides.params()?,
+        })
+    }
+
+    fn part1(fs: &Self::Input) -> Result<usize> {
+        Ok(fs.dir_sizes().filter(|&size| size <= 100000).sum())
+    }
+
+    fn part2(fs: &Self::Input) -> Result<usize> {
+        let free = fs.params.capacity.saturating_sub(fs.used());
+        let need = fs.params.update_needed.saturating_sub(free);
+
+        fs.dir_sizes()
+            .filter(|&size| size >= need)
+            .min()
+            .ok_or(Error::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{build_fs, generate, generate::Dir, Day07};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Day07::parse(INPUT).and_then(|i| Day07::part1(&i)),
+            Ok(95437)
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day07::parse(INPUT).and_then(|i| Day07::part2(&i)),
+            Ok(24933642)
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day07>(CASES, generate::input);
+    }
+
+    fn sizes(dir: &Dir, out: &mut Vec<usize>) {
+        out.push(dir.size());
+        for dir in dir.dirs.values() {
+            sizes(dir, out);
+        }
+    }
+
+    #[test]
+    fn browsing_finds_every_file() {
+        random::check(CASES, |rng| {
+            let root = generate::dir(rng, 3);
+            let fs = build_fs(&generate::terminal(rng, &root)).unwrap();
+            assert_eq!(fs.used(), root.size());
+
+            let mut expected = Vec::new();
+            sizes(&root, &mut expected);
+            expected.sort();
+            let mut found: Vec<_> = fs.dir_sizes().collect();
+            found.sort();
+            assert_eq!(found, expected);
+        });
+    }
+}
@@ -0,0 +1,14 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+/// A rectangle of tree heights.
+pub fn input(rng: &mut Rng) -> String {
+    let (width, height) = (rng.range(1..=12), rng.range(1..=12));
+    (0..height)
+        .map(|_| {
+            let row: String = (0..width).map(|_| rng.range(b'0'..=b'9') as char).collect();
+            row + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,157 @@
+use std::array;
+
+use advent::{
+    coord::Coord,
+    grid::Grid,
+    image::{self, Picture, Rgb},
+    parsers::{parse_all, parse_char_grid},
+    solution::Solution,
+    Error, Result,
+};
+
+pub mod generate;
+
+fn visibility_matrix(trees: &Grid<u32>) -> Grid<bool> {
+    let (max_x, max_y) = (trees.width() as isize - 1, trees.height() as isize - 1);
+    Grid::from_fn(trees.width(), trees.height(), |coord| {
+        coord.x == 0 || coord.y == 0 || coord.x == max_x || coord.y == max_y
+    })
+}
+
+fn look<'a>(mut trees: impl Iterator<Item = (Coord, &'a u32)>, visible: &mut Grid<bool>) {
+    let mut max_height = *trees.next().unwrap().1;
+    for (coord, &height) in trees {
+        if height > max_height {
+            visible[coord] = true;
+            max_height = height;
+
+            if max_height == 9 {
+                break;
+            }
+        }
+    }
+}
+
+fn fill_view_distances<'a>(
+    trees: impl Iterator<Item = (Coord, &'a u32)>,
+    distances: &mut Grid<usize>,
+) {
+    let mut last_seen = [0usize; 10];
+    for (idx, (coord, &tree)) in trees.enumerate() {
+        distances[coord] = idx - last_seen[tree as usize];
+
+        for height in 0..=tree {
+            last_seen[height as usize] = idx;
+        }
+    }
+}
+
+/// Which trees can be seen from outside the grid.
+pub fn visible_trees(trees: &Grid<u32>) -> Grid<bool> {
+    let mut visible = visibility_matrix(trees);
+
+    for row in trees.rows() {
+        look(row.clone(), &mut visible);
+        look(row.rev(), &mut visible);
+    }
+
+    for col in trees.columns() {
+        look(col.clone(), &mut visible);
+        look(col.rev(), &mut visible);
+    }
+
+    visible
+}
+
+/// The product of the viewing distances in each direction, for every tree.
+pub fn scenic_scores(trees: &Grid<u32>) -> Grid<usize> {
+    let mut distances: [_; 4] = array::from_fn(|_| Grid::new(trees.width(), trees.height(), 0));
+    for row in trees.rows() {
+        fill_view_distances(row.clone(), &mut distances[0]);
+        fill_view_distances(row.rev(), &mut distances[1]);
+    }
+
+    for col in trees.columns() {
+        fill_view_distances(col.clone(), &mut distances[2]);
+        fill_view_distances(col.rev(), &mut distances[3]);
+    }
+
+    Grid::from_fn(trees.width(), trees.height(), |coord| {
+        distances.iter().map(|d| d[coord]).product::<usize>()
+    })
+}
+
+const COLD: Rgb = Rgb(20, 30, 80);
+const HOT: Rgb = Rgb(250, 220, 60);
+const BEST: Rgb = Rgb(220, 30, 30);
+
+/// The scenic scores on a log scale, with the best spot marked.
+fn heatmap(scores: &Grid<usize>) -> Picture {
+    let max = scores.values().max().copied().unwrap_or(0);
+    let max_log = (max as f64).ln_1p().max(1.);
+
+    let picture = Picture::from_grid(scores, |_, &score| {
+        Some(Rgb::mix(COLD, HOT, (score as f64).ln_1p() / max_log))
+    })
+    .scale(8);
+    let picture = match scores.position(|&score| score == max) {
+        Some(best) => picture.marker(best, BEST),
+        None => picture,
+    };
+
+    picture
+        .legend("Score 0", COLD)
+        .legend(&format!("Score {}", max), HOT)
+        .legend("Best spot", BEST)
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    /// The height of every tree.
+    type Input = Grid<u32>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Grid<u32>> {
+        Ok(parse_all(parse_char_grid(|c| c.to_digit(10)), input)?)
+    }
+
+    fn part1(trees: &Grid<u32>) -> Result<usize> {
+        Ok(visible_trees(trees).values().filter(|v| **v).count())
+    }
+
+    fn part2(trees: &Grid<u32>) -> Result<usize> {
+        let scores = scenic_scores(trees);
+        image::save("day08-scenic-scores", || heatmap(&scores));
+
+        scores.values().max().copied().ok_or(Error::NoSolution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day08};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day08::parse(INPUT).and_then(|i| Day08::part1(&i)), Ok(21));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day08::parse(INPUT).and_then(|i| Day08::part2(&i)), Ok(8));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day08>(CASES, generate::input);
+    }
+}
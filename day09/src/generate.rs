@@ -0,0 +1,15 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+pub fn input(rng: &mut Rng) -> String {
+    (0..rng.range(1..=40))
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.choose(&['U', 'D', 'L', 'R']),
+                rng.range(1..=12)
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,205 @@
+use std::fmt::Display;
+
+use advent::{
+    coord::Coord,
+    debug,
+    direction::Direction,
+    parsers::{parse_lines, parse_unsigned},
+    record,
+    solution::Solution,
+    sparse_grid::SparseGrid,
+    step::{Simulation, Steppable},
+    Result,
+};
+use nom::{
+    character::complete::{anychar, char},
+    combinator::map_opt,
+    sequence::separated_pair,
+};
+
+pub mod generate;
+
+fn show_visited(visited: &SparseGrid<()>) -> impl Display + '_ {
+    visited.render(|_, cell| if cell.is_some() { '#' } else { '.' })
+}
+
+/// The rope's knots, the head as `H`, over the positions visited by one of
+/// them.
+fn show_rope<'a>(rope: &'a [Coord; 10], visited: &'a SparseGrid<()>) -> impl Display + 'a {
+    let render = visited.render(
+        |coord, cell| match rope.iter().position(|&knot| knot == coord) {
+            Some(0) => 'H',
+            Some(i) => (b'0' + i as u8) as char,
+            None if cell.is_some() => '#',
+            None => '.',
+        },
+    );
+
+    rope.iter()
+        .fold(render, |render, &knot| render.include(knot))
+}
+
+/// The rope's knots, the head first, with the positions each of them
+/// visited.
+pub struct Rope {
+    knots: [Coord; 10],
+    visited: [SparseGrid<()>; 10],
+    /// The knot whose positions are shown.
+    shown_knot: usize,
+}
+
+impl Rope {
+    pub fn new(shown_knot: usize) -> Rope {
+        let knots = [Coord::zero(); 10];
+        Rope {
+            knots,
+            visited: knots.map(|coord| SparseGrid::from_iter([coord])),
+            shown_knot,
+        }
+    }
+
+    /// Moves the head by one in `direction`, and the knots behind it.
+    pub fn pull(&mut self, direction: Direction) {
+        self.knots[0] += direction.step();
+        self.visited[0].insert(self.knots[0], ());
+
+        for i in 1..10usize {
+            let body = self.knots[i - 1] - self.knots[i];
+            if body.inf_norm() <= 1 {
+                break;
+            }
+
+            self.knots[i] += body.signum();
+            self.visited[i].insert(self.knots[i], ());
+        }
+    }
+}
+
+impl Display for Rope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        show_rope(&self.knots, &self.visited[self.shown_knot]).fmt(f)
+    }
+}
+
+/// The positions visited by every knot of the rope, the head first. Records the rope's
+/// steps over the positions visited by `shown_knot`.
+pub fn simulate(moves: &[(Direction, usize)], shown_knot: usize) -> [SparseGrid<()>; 10] {
+    let mut rope = Rope::new(shown_knot);
+
+    for &(direction, count) in moves {
+        for _ in 0..count {
+            rope.pull(direction);
+            record::frame(|| &rope);
+        }
+    }
+
+    rope.visited
+}
+
+/// The rope pulled by the head's moves, one step at a time.
+struct Pulls {
+    rope: Rope,
+    steps: Vec<Direction>,
+    next: usize,
+}
+
+impl Display for Pulls {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.rope.fmt(f)
+    }
+}
+
+impl Steppable for Pulls {
+    fn step(&mut self) -> Result<bool> {
+        let Some(&direction) = self.steps.get(self.next) else {
+            return Ok(false);
+        };
+
+        self.rope.pull(direction);
+        self.next += 1;
+        Ok(true)
+    }
+
+    fn marker(&self) -> Option<char> {
+        Some('H')
+    }
+}
+
+fn visited_by_knot(moves: &[(Direction, usize)], knot: usize, part: u8) -> usize {
+    let visited = &simulate(moves, knot)[knot];
+    debug!("Part {} movements:\n{}", part, show_visited(visited));
+
+    visited.len()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    /// The head's moves.
+    type Input = Vec<(Direction, usize)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Direction, usize)>> {
+        let direction = map_opt(anychar, Direction::from_char);
+        Ok(parse_lines(
+            separated_pair(direction, char(' '), parse_unsigned::<usize>),
+            input,
+        )?)
+    }
+
+    fn part1(moves: &Vec<(Direction, usize)>) -> Result<usize> {
+        Ok(visited_by_knot(moves, 1, 1))
+    }
+
+    fn part2(moves: &Vec<(Direction, usize)>) -> Result<usize> {
+        Ok(visited_by_knot(moves, 9, 2))
+    }
+
+    fn simulate(moves: &Vec<(Direction, usize)>, part: u8) -> Option<Simulation> {
+        let steps = moves
+            .iter()
+            .flat_map(|&(direction, count)| std::iter::repeat_n(direction, count))
+            .collect();
+
+        Some(Box::new(Pulls {
+            rope: Rope::new(if part == 1 { 1 } else { 9 }),
+            steps,
+            next: 0,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day09};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day09::parse(INPUT).and_then(|i| Day09::part1(&i)), Ok(13));
+    }
+
+    /// The second example, where the tail gets to move.
+    const LARGER_INPUT: &str = include_str!("../small-in2.txt");
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day09::parse(INPUT).and_then(|i| Day09::part2(&i)), Ok(1));
+        assert_eq!(
+            Day09::parse(LARGER_INPUT).and_then(|i| Day09::part2(&i)),
+            Ok(36)
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day09>(CASES, generate::input);
+    }
+}
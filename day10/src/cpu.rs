@@ -3,13 +3,13 @@ use crate::parser::Command;
 pub struct Cpu<'a> {
     cycle: i64,
     x: i64,
-    on_tick: Box<dyn FnMut(i64, i64) -> () + 'a>,
+    on_tick: Box<dyn FnMut(i64, i64) + 'a>,
 }
 
 impl<'a> Cpu<'a> {
     pub fn new<F>(on_tick: F) -> Cpu<'a>
     where
-        F: FnMut(i64, i64) -> () + 'a,
+        F: FnMut(i64, i64) + 'a,
     {
         Cpu {
             cycle: 1,
@@ -0,0 +1,34 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+use crate::Command;
+
+/// Enough commands to draw the whole screen, and sometimes more.
+pub fn commands(rng: &mut Rng) -> Vec<Command> {
+    let cycles = rng.range(240..=300);
+    let mut commands = Vec::new();
+    let mut cycle = 0;
+
+    while cycle < cycles {
+        if rng.chance(0.3) {
+            commands.push(Command::Noop);
+            cycle += 1;
+        } else {
+            commands.push(Command::Addx(rng.range(-20..=20)));
+            cycle += 2;
+        }
+    }
+
+    commands
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    commands(rng)
+        .into_iter()
+        .map(|cmd| match cmd {
+            Command::Noop => "noop\n".to_string(),
+            Command::Addx(x) => format!("addx {}\n", x),
+        })
+        .collect()
+}
@@ -0,0 +1,104 @@
+use advent::{parsers::parse_lines, record, solution::Solution, Result};
+pub use cpu::Cpu;
+pub use parser::{parse_command, Command};
+
+mod cpu;
+pub mod generate;
+mod parser;
+
+/// The CRT's pixels, one line per row.
+pub fn show_crt(crt: &[[char; 40]; 6]) -> String {
+    crt.map(|l| l.iter().collect::<String>()).join("\n")
+}
+
+/// The sum of the signal strengths and the picture drawn on the CRT.
+pub fn run_program(commands: &[Command]) -> (i64, [[char; 40]; 6]) {
+    let mut sum = 0i64;
+    let mut crt = [['.'; 40]; 6];
+
+    let mut cpu = Cpu::new(|cycle, x| {
+        if (cycle - 20) % 40 == 0 {
+            sum += cycle * x;
+        }
+
+        let crt_cycle = cycle - 1;
+        let (crt_y, crt_x) = (crt_cycle / 40, crt_cycle % 40);
+        // Programs running longer than the screen only affect part 1.
+        if (crt_x - x).abs() <= 1 && crt_y < crt.len() as i64 {
+            crt[crt_y as usize][crt_x as usize] = '█';
+        }
+        if crt_y < crt.len() as i64 {
+            record::frame(|| show_crt(&crt));
+        }
+    });
+
+    for cmd in commands {
+        cpu.exec_command(cmd.clone())
+    }
+
+    drop(cpu);
+    (sum, crt)
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<Command>;
+    type Answer1 = i64;
+    type Answer2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        Ok(parse_lines(parse_command, input)?)
+    }
+
+    fn part1(commands: &Vec<Command>) -> Result<i64> {
+        Ok(run_program(commands).0)
+    }
+
+    fn part2(commands: &Vec<Command>) -> Result<String> {
+        let (_, crt) = run_program(commands);
+        Ok(show_crt(&crt))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day10};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Day10::parse(INPUT).and_then(|i| Day10::part1(&i)),
+            Ok(13140)
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day10::parse(INPUT)
+                .and_then(|i| Day10::part2(&i))
+                .as_deref(),
+            Ok(concat!(
+                "██..██..██..██..██..██..██..██..██..██..\n",
+                "███...███...███...███...███...███...███.\n",
+                "████....████....████....████....████....\n",
+                "█████.....█████.....█████.....█████.....\n",
+                "██████......██████......██████......████\n",
+                "███████.......███████.......███████....."
+            ))
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day10>(CASES, generate::input);
+    }
+}
@@ -1,8 +1,9 @@
+use advent::parsers::parse_signed;
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{digit1, space1},
-    combinator::{all_consuming, map, map_res, opt, value},
+    character::complete::space1,
+    combinator::{all_consuming, map, value},
     sequence::{pair, preceded},
     IResult,
 };
@@ -23,14 +24,5 @@ fn parse_noop(input: &str) -> IResult<&str, Command> {
 
 fn parse_addx(input: &str) -> IResult<&str, Command> {
     let instr = pair(tag("addx"), space1);
-    map(preceded(instr, parse_num), |num| Command::Addx(num))(input)
-}
-
-fn parse_num(input: &str) -> IResult<&str, i64> {
-    let negative = value(-1i64, tag("-"));
-    let positive = value(1i64, opt(tag("+")));
-    let (input, sign) = alt((negative, positive))(input)?;
-
-    let (input, number) = map_res(digit1, |num: &str| num.parse::<i64>())(input)?;
-    Ok((input, sign * number))
+    map(preceded(instr, parse_signed), Command::Addx)(input)
 }
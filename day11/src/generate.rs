@@ -0,0 +1,47 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+const PRIMES: [usize; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+/// Between two and eight monkeys, each testing a different prime. Only one
+/// of them multiplies the worry levels, and never by themselves, so that
+/// they fit in a `usize` for the 20 rounds of part 1.
+pub fn input(rng: &mut Rng) -> String {
+    let count = rng.range(2..=8);
+    let primes = rng.sample(&PRIMES, count);
+    let multiplier = rng.range(0..=count - 1);
+
+    let monkeys: Vec<String> = (0..count)
+        .map(|idx| {
+            let items: Vec<String> = (0..rng.range(1..=4))
+                .map(|_| rng.range(50..=99usize).to_string())
+                .collect();
+            let op = if idx == multiplier {
+                format!("old * {}", rng.range(2..=19))
+            } else {
+                format!("old + {}", rng.range(1..=9))
+            };
+            let mut other = || (idx + rng.range(1..=count - 1)) % count;
+
+            format!(
+                concat!(
+                    "Monkey {}:\n",
+                    "  Starting items: {}\n",
+                    "  Operation: new = {}\n",
+                    "  Test: divisible by {}\n",
+                    "    If true: throw to monkey {}\n",
+                    "    If false: throw to monkey {}\n",
+                ),
+                idx,
+                items.join(", "),
+                op,
+                primes[idx],
+                other(),
+                other()
+            )
+        })
+        .collect();
+
+    monkeys.join("\n")
+}
@@ -0,0 +1,158 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use advent::{
+    ensure,
+    params::{Overrides, WithParams},
+    parsers::parse_all,
+    solution::Solution,
+    trace, usage, Error, Result,
+};
+pub use parser::{parse_monkeys, Monkey};
+
+pub mod generate;
+mod parser;
+
+/// The items held by each monkey.
+struct PrintMonkeys<'a>(&'a [VecDeque<usize>]);
+impl<'a> Display for PrintMonkeys<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, items) in self.0.iter().enumerate() {
+            let items: Vec<_> = items.iter().map(|i| i.to_string()).collect();
+            writeln!(f, "Monkey {}: {}", idx, items.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Plays `iterations` rounds, calling `adjust_worry` on each inspected item
+/// after its operation, and returns the product of the two largest numbers
+/// of inspections.
+pub fn do_monkey_business<F>(
+    monkeys: &[Monkey],
+    iterations: usize,
+    mut adjust_worry: F,
+) -> Result<usize>
+where
+    F: FnMut(usize) -> usize,
+{
+    let mut items: Vec<_> = monkeys.iter().map(|m| m.items.clone()).collect();
+    let mut inspections: Vec<_> = monkeys.iter().map(|_| 0usize).collect();
+
+    for i in 0..iterations {
+        for (src_monkey_idx, monkey) in monkeys.iter().enumerate() {
+            while let Some(item) = items[src_monkey_idx].pop_front() {
+                inspections[src_monkey_idx] += 1;
+                let item = adjust_worry(monkey.op.as_ref()(item));
+                let tgt_monkey_idx = match item.is_multiple_of(monkey.div_condition) {
+                    true => monkey.true_monkey,
+                    false => monkey.false_monkey,
+                };
+                items[tgt_monkey_idx].push_back(item)
+            }
+        }
+
+        trace!("After round {}:\n{}", i + 1, PrintMonkeys(&items));
+    }
+
+    inspections.sort();
+    inspections.reverse();
+
+    match inspections[..] {
+        [first, second, ..] => Ok(first * second),
+        _ => Err(Error::NoSolution),
+    }
+}
+
+pub struct Day11;
+
+/// Parses the monkeys, checking they only throw to each other.
+pub fn monkeys(input: &str) -> Result<Vec<Monkey>> {
+    let monkeys = parse_all(parse_monkeys, input)?;
+    for (idx, monkey) in monkeys.iter().enumerate() {
+        ensure!(
+            monkey.true_monkey < monkeys.len() && monkey.false_monkey < monkeys.len(),
+            "Monkey {} throws to a monkey that doesn't exist",
+            idx
+        );
+        ensure!(monkey.div_condition > 0, "Monkey {} divides by zero", idx);
+    }
+
+    Ok(monkeys)
+}
+
+advent::params! {
+    pub struct Params in "day11" {
+        /// The rounds played in part 1.
+        rounds: usize = 20,
+        /// The rounds played in part 2, without relief.
+        part2_rounds: usize = 10000,
+        /// What worry levels are divided by after each inspection in part 1.
+        relief: usize = 3,
+    }
+}
+
+impl Solution for Day11 {
+    type Input = WithParams<Vec<Monkey>, Params>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        let params: Params = overrides.params()?;
+        if params.relief == 0 {
+            return Err(usage!("The relief can't be zero"));
+        }
+
+        Ok(WithParams {
+            input: monkeys(input)?,
+            params,
+        })
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<usize> {
+        let relief = monkeys.params.relief;
+        do_monkey_business(monkeys, monkeys.params.rounds, |x| x / relief)
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<usize> {
+        let modulus = monkeys.iter().map(|m| m.div_condition).product::<usize>();
+        do_monkey_business(monkeys, monkeys.params.part2_rounds, move |x| x % modulus)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day11};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Day11::parse(INPUT).and_then(|i| Day11::part1(&i)),
+            Ok(10605)
+        );
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day11::parse(INPUT).and_then(|i| Day11::part2(&i)),
+            Ok(2713310158)
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day11>(CASES, generate::input);
+    }
+}
@@ -1,15 +1,20 @@
 use std::collections::VecDeque;
 
+use advent::{
+    parsers::{parse_list, parse_unsigned},
+    trace,
+};
 use nom::{
     branch::alt,
     bytes::complete::tag,
-    character::complete::{char, digit1, line_ending, space0},
-    combinator::{map, map_res, opt, value, verify},
-    multi::separated_list1,
+    character::complete::{char, line_ending, space0},
+    combinator::{cut, map, opt, value, verify},
     sequence::{delimited, pair, tuple},
     IResult,
 };
 
+/// A monkey's items, the operation on their worry level, and where it throws
+/// them depending on whether the result is divisible by `div_condition`.
 pub struct Monkey {
     pub items: VecDeque<usize>,
     pub op: Box<dyn Fn(usize) -> usize>,
@@ -18,27 +23,11 @@ pub struct Monkey {
     pub false_monkey: usize,
 }
 
-pub fn dbg_dmp<'a, F, O, E: std::fmt::Debug>(
-    f: F,
-    context: &'static str,
-) -> impl Fn(&'a str) -> IResult<&'a str, O, E>
-where
-    F: Fn(&'a str) -> IResult<&'a str, O, E>,
-{
-    move |i: &'a str| match f(i) {
-        Err(e) => {
-            println!("{}: Error({:?}) at:\n{}", context, e, i);
-            Err(e)
-        }
-        a => a,
-    }
-}
-
 pub fn parse_monkeys(mut input: &str) -> IResult<&str, Vec<Monkey>> {
     let mut monkeys: Vec<Monkey> = Vec::new();
     loop {
-        let (new_input, monkey) = opt(dbg_dmp(parse_monkey(monkeys.len()), "Opt monkey"))(input)?;
-        eprintln!("Parsed monkey: {}", monkey.is_some());
+        let (new_input, monkey) = opt(parse_monkey(monkeys.len()))(input)?;
+        trace!("Parsed monkey: {}", monkey.is_some());
         let Some(monkey) = monkey else {
             return Ok((new_input, monkeys));
         };
@@ -49,45 +38,46 @@ pub fn parse_monkeys(mut input: &str) -> IResult<&str, Vec<Monkey>> {
     }
 }
 
-fn parse_num(input: &str) -> IResult<&str, usize> {
-    map_res(digit1, |s: &str| s.parse::<usize>())(input)
-}
-
 fn parse_monkey(monkey_idx: usize) -> impl Fn(&str) -> IResult<&str, Monkey> {
     move |input| -> IResult<&str, Monkey> {
         let strip_nl = || tuple((space0, line_ending, space0));
-        let comma_sep = || tuple((space0, char(','), space0));
 
         let (input, _) = delimited(
             tag("Monkey "),
-            verify(parse_num, |idx| *idx == monkey_idx),
+            verify(parse_unsigned::<usize>, |idx| *idx == monkey_idx),
             pair(tag(":"), strip_nl()),
         )(input)?;
 
-        let (input, items) = delimited(
-            tag("Starting items: "),
-            separated_list1(comma_sep(), parse_num),
-            strip_nl(),
-        )(input)?;
-
-        let (input, op) = delimited(tag("Operation: new = "), parse_expr, strip_nl())(input)?;
-        let (input, div_condition) =
-            delimited(tag("Test: divisible by "), parse_num, strip_nl())(input)?;
-        let (input, true_monkey) =
-            delimited(tag("If true: throw to monkey "), parse_num, strip_nl())(input)?;
-        let (input, false_monkey) =
-            delimited(tag("If false: throw to monkey "), parse_num, strip_nl())(input)?;
-
-        Ok((
-            input,
-            Monkey {
-                items: VecDeque::from(items),
-                op,
-                div_condition,
-                true_monkey,
-                false_monkey,
-            },
-        ))
+        // Past the header, a failure is an error in this monkey and not the end of the list.
+        cut(move |input| -> IResult<&str, Monkey> {
+            let (input, items) = delimited(
+                tag("Starting items: "),
+                parse_list(parse_unsigned),
+                strip_nl(),
+            )(input)?;
+
+            let (input, op) = delimited(tag("Operation: new = "), parse_expr, strip_nl())(input)?;
+            let (input, div_condition) =
+                delimited(tag("Test: divisible by "), parse_unsigned, strip_nl())(input)?;
+            let (input, true_monkey) =
+                delimited(tag("If true: throw to monkey "), parse_unsigned, strip_nl())(input)?;
+            let (input, false_monkey) = delimited(
+                tag("If false: throw to monkey "),
+                parse_unsigned,
+                strip_nl(),
+            )(input)?;
+
+            Ok((
+                input,
+                Monkey {
+                    items: VecDeque::from(items),
+                    op,
+                    div_condition,
+                    true_monkey,
+                    false_monkey,
+                },
+            ))
+        })(input)
     }
 }
 
@@ -107,7 +97,7 @@ fn parse_expr(input: &str) -> IResult<&str, Box<dyn Fn(usize) -> usize>> {
     let term = || {
         alt((
             value(Term::OldRef, tag("old")),
-            map(parse_num, Term::Constant),
+            map(parse_unsigned, Term::Constant),
         ))
     };
 
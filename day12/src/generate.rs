@@ -0,0 +1,28 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+/// A random height map with a climbable path from `S` to `E`. The path snakes
+/// through the rows from the top left corner, one level up at most at each
+/// step, so it needs at least 26 squares.
+pub fn input(rng: &mut Rng) -> String {
+    let (width, height) = (rng.range(8..=20), rng.range(5..=12));
+    let mut map: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.range(b'a'..=b'z')).collect())
+        .collect();
+
+    let len = rng.range(26..=width * height);
+    for step in 0..len {
+        let (y, x) = (step / width, step % width);
+        let x = if y % 2 == 0 { x } else { width - 1 - x };
+        map[y][x] = match step {
+            0 => b'S',
+            _ if step == len - 1 => b'E',
+            _ => b'a' + (step * 25 / (len - 1)) as u8,
+        };
+    }
+
+    map.into_iter()
+        .map(|row| String::from_utf8(row).expect("Heights are letters") + "\n")
+        .collect()
+}
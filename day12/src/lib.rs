@@ -0,0 +1,137 @@
+use advent::{
+    coord::Coord,
+    debug,
+    direction::Direction,
+    grid::Grid,
+    image::{self, Picture, Rgb},
+    invalid,
+    parsers::{parse_all, parse_char_grid},
+    search::{self, bfs},
+    solution::Solution,
+    Error, Result,
+};
+
+pub mod generate;
+
+fn print_journey(maze: &Grid<u8>, path: &[Coord]) {
+    let mut journey = Grid::new(maze.width(), maze.height(), '.');
+    journey[path[0]] = 'o';
+    for step in path.windows(2) {
+        journey[step[1]] = Direction::from_step(step[0] - step[1])
+            .unwrap_or_else(|| panic!("Impossible step: {}", step[0] - step[1]))
+            .arrow();
+    }
+
+    debug!("Journey:\n{}", journey);
+}
+
+const LOW: Rgb = Rgb(46, 110, 60);
+const HIGH: Rgb = Rgb(235, 230, 220);
+const PATH: Rgb = Rgb(210, 40, 40);
+
+/// The height map from green valleys to white peaks, with the path climbed
+/// down from the top.
+fn picture(maze: &Grid<u8>, path: &[Coord]) -> Picture {
+    Picture::from_grid(maze, |_, &height| {
+        Some(Rgb::mix(LOW, HIGH, (height - b'a') as f64 / 25.))
+    })
+    .path(path.iter().copied(), PATH)
+    .marker(path[0], PATH)
+    .legend("Height a", LOW)
+    .legend("Height z", HIGH)
+    .legend("Path", PATH)
+}
+
+/// The shortest path from `start_pos` to a position accepted by `is_end`,
+/// walking the hike backwards: each step climbs down at most one level.
+pub fn search<End>(maze: &Grid<u8>, start_pos: Coord, is_end: End) -> Result<Vec<Coord>>
+where
+    End: FnMut(&Coord) -> bool,
+{
+    // Searching downhill from the top, so every step may climb down at most one level.
+    let graph = search::from_fn(|&curr: &Coord| {
+        maze.neighbours(curr)
+            .filter(move |&next| maze[curr] <= maze[next] + 1)
+    });
+
+    debug!("Searching from {}", start_pos);
+    let found = bfs(&graph, [start_pos], is_end);
+    let path = found.path().ok_or(Error::NoSolution)?;
+
+    print_journey(maze, &path);
+    Ok(path)
+}
+
+/// The heights from `b'a'` to `b'z'`, with the start and end marked on it.
+pub struct HeightMap {
+    pub maze: Grid<u8>,
+    pub start_pos: Coord,
+    pub end_pos: Coord,
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = HeightMap;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<HeightMap> {
+        let height = |c: char| c.is_ascii_alphabetic().then_some(c as u8);
+        let mut maze = parse_all(parse_char_grid(height), input)?;
+        let start_pos = maze
+            .position(|&b| b == b'S')
+            .ok_or_else(|| invalid!("No start position"))?;
+        let end_pos = maze
+            .position(|&b| b == b'E')
+            .ok_or_else(|| invalid!("No end position"))?;
+
+        maze[start_pos] = b'a';
+        maze[end_pos] = b'z';
+
+        Ok(HeightMap {
+            maze,
+            start_pos,
+            end_pos,
+        })
+    }
+
+    fn part1(map: &HeightMap) -> Result<usize> {
+        let path = search(&map.maze, map.end_pos, |&coord| coord == map.start_pos)?;
+        image::save("day12-part1", || picture(&map.maze, &path));
+        Ok(path.len() - 1)
+    }
+
+    fn part2(map: &HeightMap) -> Result<usize> {
+        let path = search(&map.maze, map.end_pos, |&coord| map.maze[coord] == b'a')?;
+        image::save("day12-part2", || picture(&map.maze, &path));
+        Ok(path.len() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day12};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day12::parse(INPUT).and_then(|i| Day12::part1(&i)), Ok(31));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day12::parse(INPUT).and_then(|i| Day12::part2(&i)), Ok(29));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day12>(CASES, generate::input);
+    }
+}
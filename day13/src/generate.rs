@@ -0,0 +1,34 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+use crate::Packet;
+
+/// A packet nested at most `depth` lists deep.
+pub fn packet(rng: &mut Rng, depth: usize) -> Packet {
+    if depth == 0 || rng.chance(0.3) {
+        return Packet::Atom(rng.range(0..=10));
+    }
+
+    Packet::List(
+        (0..rng.range(0..=4))
+            .map(|_| packet(rng, depth - 1))
+            .collect(),
+    )
+}
+
+/// A list packet, like every packet of the puzzle.
+pub fn list(rng: &mut Rng, depth: usize) -> Packet {
+    match packet(rng, depth) {
+        atom @ Packet::Atom(_) => Packet::List(vec![atom]),
+        list => list,
+    }
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    let pairs: Vec<String> = (0..rng.range(1..=10))
+        .map(|_| format!("{}\n{}\n", list(rng, 4), list(rng, 4)))
+        .collect();
+
+    pairs.join("\n")
+}
@@ -0,0 +1,173 @@
+use std::{cmp::Ordering, fmt::Display};
+
+use advent::{parsers::parse_all, solution::Solution, trace, Result};
+pub use parser::{parse_packet, parse_packet_pairs, Packet};
+
+pub mod generate;
+mod parser;
+
+/// The distress signal's order. It isn't total: a number and the list of
+/// just that number, like `[1]` and `[[1]]`, compare equal either way.
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::List(ll), Packet::List(rl)) => ll.cmp(rl),
+            (lp @ Packet::List(_), rp @ Packet::Atom(_)) => lp.cmp(&Packet::List(vec![rp.clone()])),
+            (lp @ Packet::Atom(_), rp @ Packet::List(_)) => Packet::List(vec![lp.clone()]).cmp(rp),
+            (Packet::Atom(l), Packet::Atom(r)) => l.cmp(r),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::List(l) => {
+                write!(f, "[")?;
+                for (idx, p) in l.iter().enumerate() {
+                    if idx != 0 {
+                        write!(f, ",")?;
+                    }
+
+                    write!(f, "{}", p)?;
+                }
+                write!(f, "]")?;
+            }
+            Packet::Atom(v) => write!(f, "{}", v)?,
+        }
+
+        Ok(())
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(Packet, Packet)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<(Packet, Packet)>> {
+        Ok(parse_all(parse_packet_pairs, input)?)
+    }
+
+    fn part1(packet_pairs: &Vec<(Packet, Packet)>) -> Result<usize> {
+        Ok(packet_pairs
+            .iter()
+            .enumerate()
+            .filter(|(_, (l, r))| l < r)
+            .map(|(i, _)| i + 1)
+            .sum())
+    }
+
+    fn part2(packet_pairs: &Vec<(Packet, Packet)>) -> Result<usize> {
+        let mut packets: Vec<_> = packet_pairs
+            .iter()
+            .flat_map(|(l, r)| [l.clone(), r.clone()])
+            .collect();
+
+        let dividers = [
+            parse_packet("[[2]]").unwrap().1,
+            parse_packet("[[6]]").unwrap().1,
+        ];
+
+        packets.push(dividers[0].clone());
+        packets.push(dividers[1].clone());
+        packets.sort();
+
+        let packets = packets;
+        for packet in packets.iter() {
+            trace!("{}", packet);
+        }
+
+        let divider_indices = dividers.map(|d| packets.iter().position(|p| p == &d).unwrap() + 1);
+        Ok(divider_indices.iter().product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use advent::{
+        parsers::parse_all,
+        random::{self, Rng, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, parse_packet, Day13, Packet};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day13::parse(INPUT).and_then(|i| Day13::part1(&i)), Ok(13));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day13::parse(INPUT).and_then(|i| Day13::part2(&i)), Ok(140));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day13>(CASES, generate::input);
+    }
+
+    #[test]
+    fn packets_read_back() {
+        random::check_round_trip(
+            CASES,
+            |rng| generate::packet(rng, 4),
+            Packet::to_string,
+            |packet| parse_all(parse_packet, packet),
+        );
+    }
+
+    /// Wraps numbers in lists and unwraps lists of one number, which the
+    /// distress signal can't tell apart.
+    fn disguise(rng: &mut Rng, packet: &Packet) -> Packet {
+        match packet {
+            Packet::Atom(_) if rng.chance(0.3) => Packet::List(vec![packet.clone()]),
+            Packet::List(l) if matches!(l[..], [Packet::Atom(_)]) && rng.chance(0.3) => {
+                l[0].clone()
+            }
+            Packet::List(l) => Packet::List(l.iter().map(|p| disguise(rng, p)).collect()),
+            Packet::Atom(_) => packet.clone(),
+        }
+    }
+
+    #[test]
+    fn signal_order() {
+        random::check(CASES, |rng| {
+            let mut packets: Vec<_> = (0..4).map(|_| generate::packet(rng, 3)).collect();
+            let disguised: Vec<_> = packets.iter().map(|p| disguise(rng, p)).collect();
+            for (packet, disguised) in packets.iter().zip(&disguised) {
+                assert_eq!(
+                    packet.cmp(disguised),
+                    Ordering::Equal,
+                    "{} and {}",
+                    packet,
+                    disguised
+                );
+            }
+            packets.extend(disguised);
+
+            for a in &packets {
+                for b in &packets {
+                    assert_eq!(a.cmp(b), b.cmp(a).reverse(), "{} and {}", a, b);
+
+                    for c in packets.iter().filter(|&c| a <= b && b <= c) {
+                        assert!(a <= c, "{} <= {} <= {}", a, b, c);
+                    }
+                }
+            }
+        });
+    }
+}
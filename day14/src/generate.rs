@@ -0,0 +1,34 @@
+//! Random puzzle inputs.
+
+use advent::{coord::Coord, random::Rng};
+
+/// Paths of rock below the source at `500,0`, turning at right angles.
+pub fn paths(rng: &mut Rng) -> Vec<Vec<Coord>> {
+    (0..rng.range(1..=8))
+        .map(|_| {
+            let mut pos = Coord::new(rng.range(485..=515), rng.range(2..=20));
+            let mut path = vec![pos];
+
+            for turn in 0..rng.range(1..=4) {
+                pos = if turn % 2 == 0 {
+                    Coord::new(rng.range(485..=515), pos.y)
+                } else {
+                    Coord::new(pos.x, rng.range(2..=20))
+                };
+                path.push(pos);
+            }
+
+            path
+        })
+        .collect()
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    paths(rng)
+        .iter()
+        .map(|path| {
+            let coords: Vec<String> = path.iter().map(|c| format!("{},{}", c.x, c.y)).collect();
+            coords.join(" -> ") + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,289 @@
+use std::fmt::Display;
+
+use advent::{
+    coord::Coord,
+    debug, ensure,
+    image::{self, Picture, Rgb},
+    params::{Overrides, WithParams},
+    parsers::parse_lines,
+    record,
+    solution::Solution,
+    sparse_grid::SparseGrid,
+    step::{Simulation, Steppable},
+    usage, Result,
+};
+pub use parser::parse_line;
+
+pub mod generate;
+mod parser;
+
+/// What fills a spot of the cave.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Rock,
+    Sand,
+}
+
+/// The rock drawn by the paths of `lines`.
+pub fn make_grid(lines: &[Vec<Coord>]) -> Result<SparseGrid<Tile>> {
+    let mut grid = SparseGrid::new();
+    for line in lines {
+        for (&from, &to) in line.iter().zip(line.iter().skip(1)) {
+            ensure!(
+                from.x == to.x || from.y == to.y,
+                "Rock from {} to {} isn't straight",
+                from,
+                to
+            );
+
+            let mut coord = from;
+            let step = (to - from).signum();
+
+            while coord != to {
+                grid.insert(coord, Tile::Rock);
+                coord += step;
+            }
+
+            grid.insert(coord, Tile::Rock);
+        }
+    }
+
+    ensure!(!grid.is_empty(), "There is no rock");
+    Ok(grid)
+}
+
+fn show(sandbox: &SparseGrid<Tile>, sand_drop: Coord, floor: Option<isize>) -> impl Display + '_ {
+    let render = sandbox
+        .render(move |coord, tile| match tile {
+            Some(Tile::Rock) => '█',
+            Some(Tile::Sand) => 'o',
+            None if floor == Some(coord.y) => '█',
+            None if coord == sand_drop => '+',
+            None => '.',
+        })
+        .include(sand_drop)
+        .padding(1)
+        .axis_labels(true);
+
+    match floor {
+        Some(y) => render.include(Coord::new(sand_drop.x, y)),
+        None => render,
+    }
+}
+
+const ROCK: Rgb = Rgb(90, 84, 78);
+const SAND: Rgb = Rgb(232, 188, 96);
+const SOURCE: Rgb = Rgb(200, 40, 40);
+
+fn picture(sandbox: &SparseGrid<Tile>, sand_drop: Coord, floor: Option<isize>) -> Picture {
+    let mut bounds = sandbox.bounds().with(&sand_drop);
+    if let Some(y) = floor {
+        bounds = bounds.with(&Coord::new(bounds.min.x, y));
+    }
+
+    Picture::new(bounds.expand(1), |coord| match sandbox.get(coord) {
+        Some(Tile::Rock) => Some(ROCK),
+        Some(Tile::Sand) => Some(SAND),
+        None if floor == Some(coord.y) => Some(ROCK),
+        None => None,
+    })
+    .marker(sand_drop, SOURCE)
+    .legend("Rock", ROCK)
+    .legend("Sand", SAND)
+    .legend("Source", SOURCE)
+}
+
+/// Drops a unit of sand, returning `false` if it falls below `max_y` instead
+/// of coming to rest, or once the source is blocked.
+pub fn drop_sand(sandbox: &mut SparseGrid<Tile>, sand_drop: Coord, max_y: isize) -> bool {
+    if sandbox.contains(sand_drop) {
+        return false;
+    }
+
+    let mut pos = sand_drop;
+    loop {
+        pos = if !sandbox.contains(pos + Coord::down()) {
+            pos + Coord::down()
+        } else if !sandbox.contains(pos + Coord::down() + Coord::left()) {
+            pos + Coord::down() + Coord::left()
+        } else if !sandbox.contains(pos + Coord::down() + Coord::right()) {
+            pos + Coord::down() + Coord::right()
+        } else {
+            break;
+        };
+
+        if pos.y > max_y {
+            return false;
+        }
+    }
+
+    sandbox.insert(pos, Tile::Sand);
+    true
+}
+
+/// Drops a unit of sand, which rests on the floor right below `max_y`.
+/// Returns `false` once the source is blocked.
+pub fn drop_sand_with_floor(
+    sandbox: &mut SparseGrid<Tile>,
+    sand_drop: Coord,
+    max_y: isize,
+) -> bool {
+    if sandbox.contains(sand_drop) {
+        return false;
+    }
+
+    let mut pos = sand_drop;
+    loop {
+        pos = if !sandbox.contains(pos + Coord::down()) {
+            pos + Coord::down()
+        } else if !sandbox.contains(pos + Coord::down() + Coord::left()) {
+            pos + Coord::down() + Coord::left()
+        } else if !sandbox.contains(pos + Coord::down() + Coord::right()) {
+            pos + Coord::down() + Coord::right()
+        } else {
+            break;
+        };
+
+        if pos.y > max_y {
+            break;
+        }
+    }
+
+    sandbox.insert(pos, Tile::Sand);
+    true
+}
+
+/// The cave filling up with sand, one unit at a time.
+pub struct Sandbox {
+    cave: SparseGrid<Tile>,
+    sand_drop: Coord,
+    max_y: isize,
+    /// The floor of part 2, two below the lowest rock.
+    floor: Option<isize>,
+}
+
+impl Sandbox {
+    pub fn new(grid: &<Day14 as Solution>::Input, part: u8) -> Sandbox {
+        let max_y = grid.bounds().max.y;
+        Sandbox {
+            cave: grid.input.clone(),
+            sand_drop: grid.params.sand_drop,
+            max_y,
+            floor: (part == 2).then_some(max_y + 2),
+        }
+    }
+
+    /// Drops a unit of sand, returning `false` once no more comes to rest.
+    pub fn drop(&mut self) -> bool {
+        match self.floor {
+            None => drop_sand(&mut self.cave, self.sand_drop, self.max_y),
+            Some(_) => drop_sand_with_floor(&mut self.cave, self.sand_drop, self.max_y),
+        }
+    }
+}
+
+impl Display for Sandbox {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        show(&self.cave, self.sand_drop, self.floor).fmt(f)
+    }
+}
+
+impl Steppable for Sandbox {
+    fn step(&mut self) -> Result<bool> {
+        Ok(self.drop())
+    }
+}
+
+/// Drops sand until no more comes to rest, returning how much did.
+fn fill(grid: &<Day14 as Solution>::Input, part: u8) -> Result<usize> {
+    let mut sandbox = Sandbox::new(grid, part);
+    debug!("Original grid:\n{}", sandbox);
+
+    record::frame(|| &sandbox);
+    while sandbox.drop() {
+        record::frame(|| &sandbox);
+    }
+
+    debug!("Final sandbox:\n{}", sandbox);
+    image::save(&format!("day14-part{}", part), || {
+        picture(&sandbox.cave, sandbox.sand_drop, sandbox.floor)
+    });
+    Ok(sandbox.cave.len() - grid.len())
+}
+
+pub struct Day14;
+
+advent::params! {
+    pub struct Params in "day14" {
+        /// Where the sand comes from, written as `x,y`.
+        sand_drop: Coord = Coord::new(500, 0),
+    }
+}
+
+impl Solution for Day14 {
+    /// The rock in the cave.
+    type Input = WithParams<SparseGrid<Tile>, Params>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        let lines = parse_lines(parse_line, input)?;
+        let grid = make_grid(&lines)?;
+
+        let params: Params = overrides.params()?;
+        if grid.contains(params.sand_drop) {
+            return Err(usage!(
+                "The sand can't drop from rock at {}",
+                params.sand_drop
+            ));
+        }
+
+        Ok(WithParams {
+            input: grid,
+            params,
+        })
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize> {
+        fill(grid, 1)
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize> {
+        fill(grid, 2)
+    }
+
+    fn simulate(grid: &Self::Input, part: u8) -> Option<Simulation> {
+        Some(Box::new(Sandbox::new(grid, part)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day14};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day14::parse(INPUT).and_then(|i| Day14::part1(&i)), Ok(24));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day14::parse(INPUT).and_then(|i| Day14::part2(&i)), Ok(93));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day14>(CASES, generate::input);
+    }
+}
@@ -0,0 +1,60 @@
+//! Random puzzle inputs.
+
+use std::collections::HashSet;
+
+use advent::{coord::Coord, random::Rng};
+
+use crate::SensorData;
+
+/// Sensors covering every spot from `0,0` to `bound,bound` but one, which is
+/// returned with them. Each sensor's beacon is just too close to reach it.
+pub fn sensors(rng: &mut Rng, bound: isize) -> (Vec<SensorData>, Coord) {
+    let spot = Coord::new(rng.range(0..=bound), rng.range(0..=bound));
+    let mut uncovered: HashSet<Coord> = (0..=bound)
+        .flat_map(|y| (0..=bound).map(move |x| Coord::new(x, y)))
+        .filter(|&c| c != spot)
+        .collect();
+
+    let mut sensors = Vec::new();
+    while let Some(&target) = uncovered.iter().min_by_key(|c| (c.y, c.x)) {
+        // Moving away from the spot keeps it out of reach while still
+        // covering the target.
+        let away = (target - spot).signum();
+        let sensor_pos = target + Coord::new(away.x * rng.range(1..=3), away.y * rng.range(1..=3));
+        let radius = (spot - sensor_pos).manhattan() - 1;
+
+        let dx = rng.range(0..=radius);
+        let beacon_pos = sensor_pos
+            + Coord::new(
+                dx * *rng.choose(&[-1, 1]),
+                (radius - dx) * *rng.choose(&[-1, 1]),
+            );
+
+        uncovered.retain(|&c| (c - sensor_pos).manhattan() > radius);
+        sensors.push(SensorData {
+            sensor_pos,
+            beacon_pos,
+        });
+    }
+
+    rng.shuffle(&mut sensors);
+    (sensors, spot)
+}
+
+/// The puzzle's report of `sensors`.
+pub fn report(sensors: &[SensorData]) -> String {
+    sensors
+        .iter()
+        .map(|s| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                s.sensor_pos.x, s.sensor_pos.y, s.beacon_pos.x, s.beacon_pos.y
+            )
+        })
+        .collect()
+}
+
+/// The sensors around a distress beacon within the sample's bound of 20.
+pub fn input(rng: &mut Rng) -> String {
+    report(&sensors(rng, 20).0)
+}
@@ -0,0 +1,227 @@
+use std::{num::NonZeroUsize, thread};
+
+use advent::{
+    coord::Coord,
+    debug, ensure, info,
+    intervals::{Interval, IntervalSet},
+    invalid,
+    params::Overrides,
+    parsers::parse_lines,
+    solution::Solution,
+    usage, Error, Result,
+};
+use dbg_pls::pretty;
+pub use parser::{parse_sensor, SensorData};
+
+pub mod generate;
+mod parser;
+
+/// The row and the bound asked about in the example.
+const SAMPLE_Y: isize = 10;
+const SAMPLE_BOUND: isize = 20;
+
+/// The row and the bound asked about in real inputs.
+const Y: isize = 2000000;
+const BOUND: isize = 4000000;
+
+/// The puzzle asks about a different row and bound for the example than for
+/// real inputs, without saying so in the input. The example's coordinates
+/// stay below 30 while real ones go into the millions, so an input with
+/// every sensor's coordinates below this is taken for the example.
+const SAMPLE_MAX_COORD: isize = 1000;
+
+advent::params! {
+    pub struct Params in "day15" {
+        /// The row to count in part 1: 10 for the example, 2000000 otherwise.
+        y: Option<isize> = None,
+        /// The largest coordinate of the distress beacon in part 2: 20 for
+        /// the example, 4000000 otherwise.
+        bound: Option<isize> = None,
+    }
+}
+
+/// The spots of row `y` within reach of a sensor.
+pub fn covered(data: &[SensorData], y: isize) -> IntervalSet {
+    data.iter()
+        .map(|sensor| {
+            let dist = (sensor.sensor_pos - sensor.beacon_pos).manhattan();
+            let x_span = dist - (sensor.sensor_pos.y - y).abs();
+
+            Interval::new(sensor.sensor_pos.x - x_span, sensor.sensor_pos.x + x_span)
+        })
+        .collect()
+}
+
+/// The number of spots of row `y` where the distress beacon can't be.
+pub fn count_covered_spots(data: &[SensorData], y: isize) -> usize {
+    let mut covered = covered(data, y);
+    for sensor in data.iter().filter(|s| s.beacon_pos.y == y) {
+        covered.remove(Interval::point(sensor.beacon_pos.x));
+    }
+
+    covered.len()
+}
+
+/// The only spot of row `y` between `xmin` and `xmax` out of the sensors'
+/// reach, if any.
+pub fn find_available_spot_in_line(
+    data: &[SensorData],
+    y: isize,
+    xmin: isize,
+    xmax: isize,
+) -> Result<Option<Coord>> {
+    let free = covered(data, y).complement(Interval::new(xmin, xmax));
+    ensure!(free.len() <= 1, "Free spots in line {}: {:?}", y, free);
+
+    Ok(free.iter().next().map(|spot| Coord::new(spot.start, y)))
+}
+
+/// The only spot between `min` and `max` out of the sensors' reach, if any.
+pub fn find_available_spot_in_bounds(
+    data: Vec<SensorData>,
+    min: Coord,
+    max: Coord,
+) -> Result<Option<Coord>> {
+    let spots = (min.y..=max.y)
+        .filter_map(|y| find_available_spot_in_line(&data, y, min.x, max.x).transpose())
+        .collect::<Result<Vec<_>>>()?;
+
+    ensure!(spots.len() <= 1, "Spots: {:?}", spots);
+    Ok(spots.into_iter().next())
+}
+
+/// The sensors, with the row and the bound asked about.
+pub struct Sensors {
+    pub sensors: Vec<SensorData>,
+    pub y: isize,
+    pub bound: isize,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Sensors;
+    type Answer1 = usize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Sensors> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Sensors> {
+        let sensors = parse_lines(parse_sensor, input)?;
+        let params: Params = overrides.params()?;
+
+        let is_sample = sensors
+            .iter()
+            .all(|s| s.sensor_pos.inf_norm() < SAMPLE_MAX_COORD);
+        let (y, bound) = match is_sample {
+            true => (SAMPLE_Y, SAMPLE_BOUND),
+            false => (Y, BOUND),
+        };
+        let y = params.y.unwrap_or(y);
+
+        let bound = params.bound.unwrap_or(bound);
+        if bound < 0 {
+            return Err(usage!("The bound can't be negative"));
+        }
+
+        Ok(Sensors { sensors, y, bound })
+    }
+
+    fn part1(input: &Sensors) -> Result<usize> {
+        Ok(count_covered_spots(&input.sensors, input.y))
+    }
+
+    fn part2(input: &Sensors) -> Result<isize> {
+        let bound = input.bound;
+
+        // One thread still finds the beacon if the count can't be queried.
+        let threads = thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(bound as usize / 5)
+            .max(1) as isize;
+
+        info!("Running part two on {} threads...", threads);
+
+        let count = (bound + 1) / threads + 1;
+        let threads: Vec<_> = (0..threads)
+            .map(|i| {
+                let s = input.sensors.clone();
+
+                thread::spawn(move || {
+                    let from = i * count;
+                    let to = bound.min(from + count - 1);
+
+                    find_available_spot_in_bounds(s, Coord::new(0, from), Coord::new(bound, to))
+                })
+            })
+            .collect();
+
+        let spots = threads
+            .into_iter()
+            .filter_map(|t| t.join().unwrap().transpose())
+            .collect::<Result<Vec<_>>>()?;
+
+        debug!("Free spots: {}", pretty(&spots));
+        let spot = match spots[..] {
+            [spot] => spot,
+            [] => return Err(Error::NoSolution),
+            _ => return Err(invalid!("Several free spots: {:?}", spots)),
+        };
+
+        Ok(spot.x * 4000000 + spot.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        params::Overrides,
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day15};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day15::parse(INPUT).and_then(|i| Day15::part1(&i)), Ok(26));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day15::parse(INPUT).and_then(|i| Day15::part2(&i)),
+            Ok(56000011)
+        );
+    }
+
+    #[test]
+    fn separate_defaults() {
+        let mut overrides = Overrides::default();
+        overrides.add_arg("y=11").unwrap();
+
+        let sensors = Day15::parse_with(INPUT, &overrides).unwrap();
+        assert_eq!((sensors.y, sensors.bound), (11, 20));
+        assert_eq!(Day15::part2(&sensors), Ok(56000011));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day15>(CASES, generate::input);
+    }
+
+    #[test]
+    fn finds_the_distress_beacon() {
+        random::check(CASES, |rng| {
+            let (sensors, spot) = generate::sensors(rng, 20);
+            assert_eq!(
+                Day15::parse(&generate::report(&sensors)).and_then(|i| Day15::part2(&i)),
+                Ok(spot.x * 4000000 + spot.y)
+            );
+        });
+    }
+}
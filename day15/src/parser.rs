@@ -1,8 +1,8 @@
-use advent::{coord::Coord, parsers::parse_signed};
+use advent::{coord::Coord, parsers::parse_xy};
 use nom::{
     bytes::complete::tag,
     combinator::map,
-    sequence::{pair, preceded, separated_pair},
+    sequence::{pair, preceded},
     IResult,
 };
 
@@ -15,8 +15,8 @@ pub struct SensorData {
 pub fn parse_sensor(input: &str) -> IResult<&str, SensorData> {
     map(
         pair(
-            preceded(tag("Sensor at "), parse_coord),
-            preceded(tag(": closest beacon is at "), parse_coord),
+            preceded(tag("Sensor at "), parse_xy),
+            preceded(tag(": closest beacon is at "), parse_xy),
         ),
         |(sensor_pos, beacon_pos)| SensorData {
             sensor_pos,
@@ -24,14 +24,3 @@ pub fn parse_sensor(input: &str) -> IResult<&str, SensorData> {
         },
     )(input)
 }
-
-fn parse_coord(input: &str) -> IResult<&str, Coord> {
-    map(
-        separated_pair(
-            preceded(tag("x="), parse_signed),
-            tag(", "),
-            preceded(tag("y="), parse_signed),
-        ),
-        |(x, y)| Coord::new(x, y),
-    )(input)
-}
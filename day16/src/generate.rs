@@ -0,0 +1,76 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+use crate::Valve;
+
+fn labels(rng: &mut Rng, count: usize) -> Vec<String> {
+    let mut labels = vec!["AA".to_string()];
+    while labels.len() < count {
+        let label: String = (0..2).map(|_| rng.range(b'A'..=b'Z') as char).collect();
+        if !labels.contains(&label) {
+            labels.push(label);
+        }
+    }
+
+    labels
+}
+
+/// A connected network of valves, at most six of them worth opening so that
+/// searching every order stays quick.
+pub fn valves(rng: &mut Rng) -> Vec<Valve> {
+    let count = rng.range(2..=12);
+    let labels = labels(rng, count);
+    let working_count = rng.range(1..=6);
+    let working = rng.sample(&(1..count).collect::<Vec<_>>(), working_count);
+    let mut neighbours = vec![Vec::new(); count];
+    let mut link = |a: usize, b: usize| {
+        if a != b && !neighbours[a].contains(&b) {
+            neighbours[a].push(b);
+            neighbours[b].push(a);
+        }
+    };
+
+    // A random tree keeps every valve reachable, then a few more tunnels
+    // make loops.
+    for valve in 1..count {
+        link(valve, rng.range(0..=valve - 1));
+    }
+    for _ in 0..rng.range(0..=count) {
+        link(rng.range(0..=count - 1), rng.range(0..=count - 1));
+    }
+
+    let mut valves: Vec<Valve> = (0..count)
+        .map(|idx| Valve {
+            label: labels[idx].clone(),
+            flow_rate: if working.contains(&idx) {
+                rng.range(1..=25)
+            } else {
+                0
+            },
+            neighbours: neighbours[idx].iter().map(|&n| labels[n].clone()).collect(),
+        })
+        .collect();
+
+    rng.shuffle(&mut valves);
+    valves
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    valves(rng)
+        .iter()
+        .map(|valve| {
+            let tunnels = match valve.neighbours.len() {
+                1 => "tunnel leads to valve",
+                _ => "tunnels lead to valves",
+            };
+            format!(
+                "Valve {} has flow rate={}; {} {}\n",
+                valve.label,
+                valve.flow_rate,
+                tunnels,
+                valve.neighbours.join(", ")
+            )
+        })
+        .collect()
+}
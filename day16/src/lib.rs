@@ -0,0 +1,284 @@
+use advent::{
+    debug, ensure,
+    params::{Overrides, WithParams},
+    parsers::parse_lines,
+    search::{self, bfs},
+    solution::Solution,
+    Result,
+};
+use bitvec::vec::BitVec;
+use std::{
+    cmp::Reverse,
+    collections::{BTreeMap, HashMap, HashSet, VecDeque},
+    fmt::Display,
+};
+
+pub use parser::{parse_valve, Valve};
+
+pub mod generate;
+mod parser;
+
+/// The number of steps between every pair of `nodes`.
+pub fn dist_matrix(valves: &[Valve], nodes: &[(String, isize)]) -> Vec<Vec<isize>> {
+    let mut dists = vec![vec![isize::MAX; nodes.len()]; nodes.len()];
+
+    let valves: BTreeMap<String, Valve> = valves
+        .iter()
+        .cloned()
+        .map(|v| (v.label.clone(), v))
+        .collect();
+
+    let graph = search::from_fn(|&node: &&str| valves[node].neighbours.iter().map(String::as_str));
+
+    for (src_node_idx, src_node) in nodes.iter().enumerate() {
+        let reached = bfs(&graph, [src_node.0.as_str()], |_| false);
+
+        for (dst_node_idx, dst_node) in nodes.iter().enumerate() {
+            if let Some(d) = reached.cost_to(&dst_node.0.as_str()) {
+                dists[src_node_idx][dst_node_idx] = d as isize;
+            }
+        }
+    }
+
+    dists
+}
+
+struct Dot<'a>(&'a Vec<Valve>);
+impl<'a> Display for Dot<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "graph g {{")?;
+
+        for valve in self.0.iter() {
+            if valve.flow_rate == 0 {
+                writeln!(f, r#"  {}[label="{}"]"#, valve.label, valve.label)?;
+            } else {
+                writeln!(
+                    f,
+                    r#"  {}[label="{}: {}", style=filled]"#,
+                    valve.label, valve.label, valve.flow_rate
+                )?;
+            }
+        }
+
+        let mut printed = HashSet::new();
+        for valve in self.0.iter() {
+            printed.insert(&valve.label);
+
+            for neigh in valve.neighbours.iter() {
+                if !printed.contains(neigh) {
+                    writeln!(f, r#"  {} -- {}"#, valve.label, neigh)?;
+                }
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+#[derive(Clone, PartialEq, Eq)]
+struct FlowState {
+    time: isize,
+    flow_per_min: isize,
+    total_flow: isize,
+    open_valves: BitVec,
+}
+
+/// The most pressure released by opening each set of valves within
+/// `max_time` minutes, in the best order. Every set has the first node,
+/// where the opening starts.
+fn best_flows(
+    nodes: &[(String, isize)],
+    dists: &[Vec<isize>],
+    max_time: isize,
+) -> HashMap<BitVec, isize> {
+    let mut start: BitVec = BitVec::repeat(false, nodes.len());
+    start.set(0, true);
+
+    let mut best_flows = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(
+        0usize,
+        FlowState {
+            time: 0,
+            flow_per_min: 0,
+            total_flow: 0,
+            open_valves: start,
+        },
+    )]);
+
+    while let Some((idx, flow)) = queue.pop_back() {
+        for neigh in 0..nodes.len() {
+            if flow.open_valves[neigh] {
+                continue;
+            }
+
+            let dist = dists[idx][neigh] + 1;
+            let new_time = flow.time + dist;
+            if new_time > max_time {
+                continue;
+            }
+
+            let new_flow = FlowState {
+                time: new_time,
+                total_flow: flow.total_flow + flow.flow_per_min * dist,
+                flow_per_min: flow.flow_per_min + nodes[neigh].1,
+                open_valves: {
+                    let mut v = flow.open_valves.clone();
+                    v.set(neigh, true);
+                    v
+                },
+            };
+
+            let new_pred_flow =
+                new_flow.total_flow + new_flow.flow_per_min * (max_time - new_flow.time);
+            let best = best_flows
+                .entry(new_flow.open_valves.clone())
+                .or_insert(new_pred_flow);
+            *best = (*best).max(new_pred_flow);
+
+            queue.push_back((neigh, new_flow));
+        }
+    }
+
+    best_flows
+}
+
+/// The most pressure released in `max_time` minutes, starting from the first
+/// node.
+pub fn best_flow(nodes: &[(String, isize)], dists: &[Vec<isize>], max_time: isize) -> isize {
+    best_flows(nodes, dists, max_time)
+        .into_values()
+        .max()
+        .unwrap_or_default()
+}
+
+/// The most pressure released with the help of an elephant, which opens
+/// other valves at the same time.
+pub fn best_flow_with_elephant(
+    nodes: &[(String, isize)],
+    dists: &[Vec<isize>],
+    max_time: isize,
+) -> isize {
+    let mut flows: Vec<_> = best_flows(nodes, dists, max_time).into_iter().collect();
+    flows.sort_unstable_by_key(|&(_, flow)| Reverse(flow));
+
+    let mut best = 0;
+    for (i, (mine, my_flow)) in flows.iter().enumerate() {
+        if my_flow * 2 <= best {
+            break;
+        }
+
+        for (theirs, their_flow) in &flows[i..] {
+            if my_flow + their_flow <= best {
+                break;
+            }
+
+            // Both start from the first node, the only one they can share.
+            if !mine.iter_ones().any(|valve| valve != 0 && theirs[valve]) {
+                best = my_flow + their_flow;
+            }
+        }
+    }
+
+    best
+}
+
+/// The valves worth opening, with the distances between them.
+pub struct Network {
+    /// Each valve's label and flow rate, `AA` first.
+    pub nodes: Vec<(String, isize)>,
+    pub dists: Vec<Vec<isize>>,
+}
+
+pub fn network(input: &str) -> Result<Network> {
+    let valves = parse_lines(parse_valve, input)?;
+
+    let labels: HashSet<_> = valves.iter().map(|v| &v.label).collect();
+    ensure!(labels.contains(&"AA".to_string()), "There is no valve AA");
+    for valve in valves.iter() {
+        for neigh in valve.neighbours.iter() {
+            ensure!(
+                labels.contains(neigh),
+                "Valve {} leads to unknown valve {}",
+                valve.label,
+                neigh
+            );
+        }
+    }
+
+    debug!("The valves as a DOT graph:\n{}", Dot(&valves));
+
+    let mut nodes: Vec<_> = valves
+        .iter()
+        .filter(|v| v.label == "AA" || v.flow_rate > 0)
+        .map(|v| (v.label.clone(), v.flow_rate))
+        .collect();
+
+    nodes.sort();
+
+    let dists = dist_matrix(&valves, &nodes);
+    Ok(Network { nodes, dists })
+}
+
+advent::params! {
+    pub struct Params in "day16" {
+        /// The minutes before the volcano erupts.
+        minutes: isize = 30,
+        /// The minutes left after teaching the elephant, in part 2.
+        part2_minutes: isize = 26,
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = WithParams<Network, Params>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        Ok(WithParams {
+            input: network(input)?,
+            params: overrides.params()?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<isize> {
+        Ok(best_flow(&input.nodes, &input.dists, input.params.minutes))
+    }
+
+    fn part2(input: &Self::Input) -> Result<isize> {
+        let minutes = input.params.part2_minutes;
+        Ok(best_flow_with_elephant(&input.nodes, &input.dists, minutes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day16};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day16::parse(INPUT).and_then(|i| Day16::part1(&i)), Ok(1651));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day16::parse(INPUT).and_then(|i| Day16::part2(&i)), Ok(1707));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day16>(CASES, generate::input);
+    }
+}
@@ -0,0 +1,34 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+use crate::{Chamber, Snapshot};
+
+/// Whether the top of the tower can be traced once it grew a bit, which
+/// finding its cycle relies on. Jets that push one way much more than the
+/// other leave a column empty forever, and the trace never reaches its end.
+fn can_trace(jets: &str) -> bool {
+    let mut chamber = Chamber::new(jets, 7).expect("Generated jets are valid");
+    for _ in 0..500 {
+        chamber.drop_rock();
+    }
+
+    (0..100).any(|_| {
+        chamber.drop_rock();
+        Snapshot::take(&chamber).is_some()
+    })
+}
+
+/// The jets of hot gas, as many pushing left as right like the puzzle's.
+pub fn input(rng: &mut Rng) -> String {
+    loop {
+        let half = rng.range(10..=100);
+        let mut jets: Vec<char> = [vec!['<'; half], vec!['>'; half]].concat();
+        rng.shuffle(&mut jets);
+
+        let jets: String = jets.into_iter().collect();
+        if can_trace(&jets) {
+            return jets + "\n";
+        }
+    }
+}
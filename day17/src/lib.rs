@@ -0,0 +1,512 @@
+use std::{collections::HashSet, fmt::Display};
+
+use advent::{
+    coord::Coord,
+    cycle::find_cycle,
+    direction::Direction,
+    params::{Overrides, WithParams},
+    parsers::parse_all,
+    record,
+    solution::Solution,
+    step::{Simulation, Steppable},
+    usage, Result,
+};
+use nom::{character::complete::one_of, combinator::map_opt, multi::many1};
+
+pub mod generate;
+
+/// The rows at the top of the chamber shown in recorded frames.
+const SHOWN_ROWS: isize = 30;
+
+/// How many times part 2 goes through every jet with every rock before
+/// giving up on the tower repeating. The inputs seen so far repeat within
+/// two.
+const CYCLE_ROUNDS: usize = 20;
+
+/*
+ * All the different possible rock formations:
+ *           ####
+ *
+ *           .#.
+ *           ###
+ *           .#.
+ *
+ *           ..#
+ *           ..#
+ *           ###
+ *
+ *           #
+ *           #
+ *           #
+ *           #
+ *
+ *           ##
+ *           ##
+ */
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rock {
+    /// ####
+    Minus,
+
+    /// .#.
+    /// ###
+    /// .#.
+    Plus,
+
+    /// ..#
+    /// ..#
+    /// ###
+    ArrowHead,
+
+    /// #
+    /// #
+    /// #
+    /// #
+    I,
+
+    /// ##
+    /// ##
+    Dot,
+}
+
+/// Rocks appear two units from the left wall, so the widest one needs this
+/// much room.
+const MIN_CHAMBER_WIDTH: isize = 6;
+
+/// The chamber grows upwards, so the trace uses `Direction::step_y_up`. Each
+/// step is stored in two bits.
+fn trace_bits(dir: Direction) -> u64 {
+    match dir {
+        Direction::North => 0b00,
+        Direction::South => 0b01,
+        Direction::West => 0b10,
+        Direction::East => 0b11,
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+struct Snapshot {
+    rock_idx: usize,
+    step_idx: usize,
+    trace: u64,
+}
+
+impl Snapshot {
+    fn trace(chamber: &Chamber) -> Option<u64> {
+        let mut pos = Coord::new(0, chamber.highest_ys[0] + 1);
+        let mut curr_dir = Direction::East;
+        let mut res = 0u64;
+
+        let max_steps = std::mem::size_of_val(&res) * u8::BITS as usize / 2;
+        for _ in 0..max_steps {
+            if chamber.is_free(pos + curr_dir.clockwise().step_y_up()) {
+                // Try to turn right first
+                curr_dir = curr_dir.clockwise();
+            } else if chamber.is_free(pos + curr_dir.step_y_up()) {
+                // Else try to step forward (or we have reached the end)
+            } else if chamber.is_free(pos + curr_dir.anticlockwise().step_y_up()) {
+                // Else try to turn left
+                curr_dir = curr_dir.anticlockwise();
+            } else {
+                // Otherwise we must be able to go back
+                assert!(chamber.is_free(pos + curr_dir.opposite().step_y_up()));
+                curr_dir = curr_dir.opposite();
+            }
+
+            pos += curr_dir.step_y_up();
+            res = (res << 2) | trace_bits(curr_dir);
+
+            let last_x = chamber.width - 1;
+            if pos == Coord::new(last_x, chamber.highest_ys[last_x as usize] + 1) {
+                return Some(res);
+            }
+        }
+
+        None
+    }
+
+    fn take(chamber: &Chamber) -> Option<Snapshot> {
+        Self::trace(chamber).map(|trace| Snapshot {
+            rock_idx: chamber.rock_idx % Rock::all().len(),
+            step_idx: chamber.step_idx % chamber.steps.len(),
+            trace,
+        })
+    }
+}
+
+/// The rocks that came to rest, the jets of gas, and how many of both were
+/// used so far.
+#[derive(Clone)]
+pub struct Chamber {
+    chamber: Vec<HashSet<isize>>,
+    highest_ys: Vec<isize>,
+    width: isize,
+    steps: Vec<Direction>,
+    step_idx: usize,
+    rock_idx: usize,
+}
+
+impl Chamber {
+    /// An empty chamber, with the jets written as `<` and `>`.
+    pub fn new(winds: &str, width: isize) -> Result<Chamber> {
+        let wind = map_opt(one_of("<>"), Direction::from_char);
+
+        Ok(Chamber {
+            chamber: vec![HashSet::new(); width as usize],
+            highest_ys: vec![-1; width as usize],
+            width,
+            steps: parse_all(many1(wind), winds)?,
+            step_idx: 0,
+            rock_idx: 0,
+        })
+    }
+
+    /// The height of the top of the tower, -1 while there's no rock.
+    pub fn highest_y(&self) -> isize {
+        *self.highest_ys.iter().max().unwrap()
+    }
+
+    pub fn is_free(&self, coord: Coord) -> bool {
+        if coord.y < 0 || coord.x < 0 || coord.x >= self.width {
+            return false;
+        }
+
+        !self.chamber[coord.x as usize].contains(&coord.y)
+    }
+
+    fn fill_block(&mut self, coord: Coord) {
+        assert!(
+            self.chamber[coord.x as usize].insert(coord.y),
+            "Attempting to add rock to already filled coord: {}",
+            coord
+        );
+
+        self.highest_ys[coord.x as usize] = self.highest_ys[coord.x as usize].max(coord.y);
+    }
+
+    fn can_add_rock(&self, rock: Rock, origin_pos: Coord) -> bool {
+        rock.coords_at(origin_pos).all(|c| self.is_free(c))
+    }
+
+    fn add_rock(&mut self, rock: Rock, origin_pos: Coord) {
+        rock.coords_at(origin_pos)
+            .for_each(|coord| self.fill_block(coord))
+    }
+
+    fn spawn_pos(&self) -> Coord {
+        Coord::new(2, self.highest_y() + 4)
+    }
+
+    /// The next rock, where it appears.
+    fn spawn_rock(&mut self) -> (Rock, Coord) {
+        let rock = Rock::all()[self.rock_idx % Rock::all().len()];
+        self.rock_idx += 1;
+
+        (rock, self.spawn_pos())
+    }
+
+    /// Pushes the falling rock with the next jet, then lets it fall by one.
+    /// Returns where it is then, or `None` once it came to rest.
+    fn move_rock(&mut self, rock: Rock, rock_pos: Coord) -> Option<Coord> {
+        let step = self.steps[self.step_idx % self.steps.len()];
+        self.step_idx += 1;
+
+        let mut rock_pos = rock_pos;
+        let new_coord = rock_pos + step.step_y_up();
+        if self.can_add_rock(rock, new_coord) {
+            rock_pos = new_coord;
+        }
+
+        let new_coord = rock_pos + Direction::South.step_y_up();
+        if self.can_add_rock(rock, new_coord) {
+            Some(new_coord)
+        } else {
+            self.add_rock(rock, rock_pos);
+            None
+        }
+    }
+
+    /// Drops the next rock until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        let (rock, mut rock_pos) = self.spawn_rock();
+
+        loop {
+            record::frame(|| ShowChamber(self, Some((rock, rock_pos))));
+
+            match self.move_rock(rock, rock_pos) {
+                Some(new_pos) => rock_pos = new_pos,
+                None => {
+                    record::frame(|| ShowChamber(self, None));
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// The rocks falling in the chamber, one move at a time.
+struct Falling {
+    chamber: Chamber,
+    /// The falling rock, if any, and where it is.
+    rock: Option<(Rock, Coord)>,
+    /// The rocks still to drop, the falling one included.
+    rocks_left: usize,
+}
+
+impl Display for Falling {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        ShowChamber(&self.chamber, self.rock).fmt(f)
+    }
+}
+
+impl Steppable for Falling {
+    fn step(&mut self) -> Result<bool> {
+        self.rock = match self.rock {
+            Some((rock, rock_pos)) => {
+                let moved = self.chamber.move_rock(rock, rock_pos);
+                if moved.is_none() {
+                    self.rocks_left -= 1;
+                }
+
+                moved.map(|new_pos| (rock, new_pos))
+            }
+            None if self.rocks_left > 0 => Some(self.chamber.spawn_rock()),
+            None => return Ok(false),
+        };
+
+        Ok(true)
+    }
+
+    fn marker(&self) -> Option<char> {
+        Some('@')
+    }
+}
+
+impl Rock {
+    /// The rocks in the order they fall.
+    pub fn all() -> &'static [Rock; 5] {
+        use Rock::*;
+        &[Minus, Plus, ArrowHead, I, Dot]
+    }
+
+    fn coords(&self) -> &'static [Coord] {
+        macro_rules! coords {
+            ( $( ($x:expr, $y:expr) ),* ) => {
+                {
+                    const COORDS: &[Coord] = &[$( Coord::new($x, $y) ),*];
+                    COORDS
+                }
+            };
+        }
+        match self {
+            Rock::Minus => coords![(0, 0), (1, 0), (2, 0), (3, 0)],
+            Rock::Plus => coords![(1, 1), (0, 1), (1, 0), (2, 1), (1, 2)],
+            Rock::ArrowHead => coords![(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+            Rock::I => coords![(0, 0), (0, 1), (0, 2), (0, 3)],
+            Rock::Dot => coords![(0, 0), (1, 0), (1, 1), (0, 1)],
+        }
+    }
+
+    /// The spots taken by the rock when its bottom left corner is at
+    /// `origin_pos`.
+    pub fn coords_at(&self, origin_pos: Coord) -> impl Iterator<Item = Coord> {
+        self.coords().iter().map(move |c| *c + origin_pos)
+    }
+}
+
+struct ShowChamber<'a>(&'a Chamber, Option<(Rock, Coord)>);
+impl<'a> Display for ShowChamber<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let max_chamber_y = self.0.highest_y();
+        let rock_coords = self
+            .1
+            .map(|(rock, coord)| rock.coords_at(coord).collect::<Vec<_>>());
+
+        let max_rock_y = rock_coords
+            .as_ref()
+            .map(|coords| coords.iter().map(|c| c.y).max().unwrap())
+            .unwrap_or(max_chamber_y);
+
+        let max_y = max_chamber_y.max(max_rock_y);
+        let min_y = (max_y - SHOWN_ROWS + 1).max(0);
+
+        for y in (min_y..=max_y).rev() {
+            write!(f, "|")?;
+            for x in 0..self.0.width {
+                if rock_coords
+                    .as_ref()
+                    .filter(|coords| coords.contains(&Coord::new(x, y)))
+                    .is_some()
+                {
+                    write!(f, "@")?;
+                } else if self.0.is_free(Coord::new(x, y)) {
+                    write!(f, ".")?;
+                } else {
+                    write!(f, "#")?;
+                }
+            }
+
+            writeln!(f, "|")?;
+        }
+
+        if min_y == 0 {
+            writeln!(f, "+{}+", "-".repeat(self.0.width as usize))
+        } else {
+            writeln!(f, "|{}|", "~".repeat(self.0.width as usize))
+        }
+    }
+}
+
+pub struct Day17;
+
+advent::params! {
+    pub struct Params in "day17" {
+        /// The rocks dropped in part 1.
+        rocks: usize = 2022,
+        /// The rocks dropped in part 2.
+        part2_rocks: usize = 1000000000000,
+        /// The width of the chamber.
+        width: isize = 7,
+    }
+}
+
+impl Solution for Day17 {
+    type Input = WithParams<Chamber, Params>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        let params: Params = overrides.params()?;
+        if params.width < MIN_CHAMBER_WIDTH {
+            return Err(usage!(
+                "The chamber must be at least {} wide",
+                MIN_CHAMBER_WIDTH
+            ));
+        }
+
+        Ok(WithParams {
+            input: Chamber::new(input, params.width)?,
+            params,
+        })
+    }
+
+    fn part1(chamber: &Self::Input) -> Result<isize> {
+        let rocks = chamber.params.rocks;
+        let mut chamber = chamber.input.clone();
+
+        for _ in 0..rocks {
+            chamber.drop_rock();
+        }
+
+        Ok(chamber.highest_y() + 1)
+    }
+
+    fn part2(chamber: &Self::Input) -> Result<isize> {
+        let cycle = find_cycle(
+            chamber.input.clone(),
+            Chamber::drop_rock,
+            Snapshot::take,
+            Chamber::highest_y,
+            CYCLE_ROUNDS * Rock::all().len() * chamber.input.steps.len(),
+        )?;
+
+        Ok(cycle.measure_at(chamber.params.part2_rocks) + 1)
+    }
+
+    fn simulate(chamber: &Self::Input, part: u8) -> Option<Simulation> {
+        let rocks_left = match part {
+            1 => chamber.params.rocks,
+            _ => chamber.params.part2_rocks,
+        };
+
+        Some(Box::new(Falling {
+            chamber: chamber.input.clone(),
+            rock: None,
+            rocks_left,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        Error,
+    };
+
+    use super::*;
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn test_tracing() {
+        let mut ch = Chamber::new("<", 7).unwrap();
+
+        macro_rules! fill {
+            ( $( ($x:expr, $y:expr) ),* ) => {
+                $( fill!($x, $y); )*
+            };
+
+            ( $x:expr, $y:expr ) => {
+                ch.fill_block(Coord::new($x, $y))
+            };
+        }
+
+        assert_eq!(Snapshot::trace(&ch), Some(0b11_11_11_11_11_11));
+
+        fill!(0, 0);
+        assert_eq!(Snapshot::trace(&ch), Some(0b11_01_11_11_11_11_11));
+
+        /* .......
+         * .##....
+         * ..#....
+         * #.#.... */
+        fill![(2, 0), (2, 1), (2, 2), (1, 2)];
+        assert_eq!(
+            Snapshot::trace(&ch),
+            Some(0b11_01_00_10_00_00_11_11_11_01_01_01_11_11_11)
+        );
+
+        /* .......
+         * #......
+         * .##....
+         * ..#....
+         * #.#.... */
+        fill!(0, 3);
+        assert_eq!(Snapshot::trace(&ch), Some(0b11_01_11_11_01_01_01_11_11_11));
+    }
+
+    #[test]
+    fn test_part1() {
+        assert_eq!(Day17::parse(INPUT).and_then(|i| Day17::part1(&i)), Ok(3068));
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(
+            Day17::parse(INPUT).and_then(|i| Day17::part2(&i)),
+            Ok(1514285714288)
+        );
+    }
+
+    #[test]
+    fn test_part2_without_cycle() {
+        // The jets push every rock against the right wall, so the left of
+        // the chamber stays open and the top of the tower soon can't be
+        // traced at all.
+        assert_eq!(
+            Day17::parse(">\n").and_then(|i| Day17::part2(&i)),
+            Err(Error::NoSolution)
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day17>(CASES, generate::input);
+    }
+}
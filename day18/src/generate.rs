@@ -0,0 +1,29 @@
+//! Random puzzle inputs.
+
+use std::collections::HashSet;
+
+use advent::{coord3::Coord3, random::Rng};
+
+/// Cubes within a small box, dense enough to trap some air.
+pub fn droplet(rng: &mut Rng) -> HashSet<Coord3> {
+    let size = rng.range(1..=6);
+    let density = rng.range(2..=8) as f64 / 10.;
+
+    let mut cubes: HashSet<Coord3> = (0..size * size * size)
+        .filter(|_| rng.chance(density))
+        .map(|i| Coord3::new(i % size, i / size % size, i / size / size))
+        .collect();
+    cubes.insert(Coord3::new(0, 0, 0));
+    cubes
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    let mut cubes: Vec<Coord3> = droplet(rng).into_iter().collect();
+    cubes.sort_by_key(|c| (c.x, c.y, c.z));
+    rng.shuffle(&mut cubes);
+
+    cubes
+        .iter()
+        .map(|c| format!("{},{},{}\n", c.x, c.y, c.z))
+        .collect()
+}
@@ -0,0 +1,187 @@
+use std::collections::{HashSet, VecDeque};
+
+use advent::{
+    bounds::{Bounds, Bounds3, CoordBounded},
+    coord::Coord,
+    coord3::Coord3,
+    ensure,
+    image::{self, Picture, Rgb},
+    parsers::parse_all,
+    search::{self, bfs},
+    solution::Solution,
+    Result,
+};
+pub use parser::parse_drop;
+
+#[allow(unused_imports)]
+use dbg_pls::pretty;
+
+pub mod generate;
+mod parser;
+
+const LAVA: Rgb = Rgb(224, 96, 32);
+const POCKET: Rgb = Rgb(60, 110, 210);
+const OUTSIDE: Rgb = Rgb(225, 225, 225);
+
+/// Every z slice of the droplet, side by side, showing the lava and the air
+/// pockets trapped inside.
+fn slices(
+    drop: &HashSet<Coord3>,
+    bounds: Bounds3,
+    is_outside: impl Fn(&Coord3) -> bool,
+) -> Picture {
+    let size = bounds.max - bounds.min + Coord3::new(1, 1, 1);
+    let columns = (size.z as f64).sqrt().ceil() as isize;
+    let rows = (size.z + columns - 1) / columns;
+    // One empty cell between the slices.
+    let (tile_width, tile_height) = (size.x + 1, size.y + 1);
+
+    let picture_bounds = Bounds {
+        min: Coord::zero(),
+        max: Coord::new(columns * tile_width - 2, rows * tile_height - 2),
+    };
+
+    Picture::new(picture_bounds, |coord| {
+        let (x, y) = (coord.x % tile_width, coord.y % tile_height);
+        let z = coord.y / tile_height * columns + coord.x / tile_width;
+        if x == size.x || y == size.y || z >= size.z {
+            return None;
+        }
+
+        let coord = bounds.min + Coord3::new(x, y, z);
+        Some(if drop.contains(&coord) {
+            LAVA
+        } else if is_outside(&coord) {
+            OUTSIDE
+        } else {
+            POCKET
+        })
+    })
+    .scale(6)
+    .legend("Lava", LAVA)
+    .legend("Air pocket", POCKET)
+    .legend("Outside", OUTSIDE)
+}
+
+/// The area of every side of a cube that isn't against another cube.
+pub fn surface_area(drop: &HashSet<Coord3>) -> isize {
+    let mut seen: HashSet<Coord3> = HashSet::new();
+    let mut surface_area = 0isize;
+
+    for &coord in drop.iter() {
+        if seen.contains(&coord) {
+            continue;
+        }
+
+        let mut queue = VecDeque::from([coord]);
+        seen.insert(coord);
+
+        while let Some(coord) = queue.pop_front() {
+            for neighbour in coord.orthogonal_neighbours() {
+                if !drop.contains(&neighbour) {
+                    surface_area += 1;
+                    continue;
+                }
+
+                if seen.contains(&neighbour) {
+                    continue;
+                }
+
+                seen.insert(neighbour);
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    surface_area
+}
+
+/// The area of the sides reachable from outside the droplet, leaving out
+/// the air pockets trapped inside.
+pub fn exterior_surface_area(drop: &HashSet<Coord3>) -> Result<isize> {
+    ensure!(!drop.is_empty(), "The droplet is empty");
+    let bounds = drop.iter().coord_bounds().expand(1);
+
+    let outside = search::from_fn(|&coord: &Coord3| {
+        coord
+            .orthogonal_neighbours()
+            .filter(|neighbour| bounds.contains(neighbour))
+            .filter(|neighbour| !drop.contains(neighbour))
+    });
+
+    let outside = bfs(&outside, [bounds.min], |_| false);
+    image::save("day18-slices", || {
+        slices(drop, drop.iter().coord_bounds(), |c| outside.contains(c))
+    });
+
+    Ok(outside
+        .reached()
+        .flat_map(|coord| coord.orthogonal_neighbours())
+        .filter(|neighbour| drop.contains(neighbour))
+        .count() as isize)
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = HashSet<Coord3>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<HashSet<Coord3>> {
+        Ok(parse_all(parse_drop, input)?)
+    }
+
+    fn part1(drop: &HashSet<Coord3>) -> Result<isize> {
+        Ok(surface_area(drop))
+    }
+
+    fn part2(drop: &HashSet<Coord3>) -> Result<isize> {
+        exterior_surface_area(drop)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{exterior_surface_area, generate, surface_area, Day18};
+
+    const TINY_INPUT: &str = "1,1,1\n2,1,1\n";
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1_tiny() {
+        assert_eq!(
+            Day18::parse(TINY_INPUT).and_then(|i| Day18::part1(&i)),
+            Ok(10)
+        );
+    }
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day18::parse(INPUT).and_then(|i| Day18::part1(&i)), Ok(64));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day18::parse(INPUT).and_then(|i| Day18::part2(&i)), Ok(58));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day18>(CASES, generate::input);
+    }
+
+    #[test]
+    fn exterior_is_part_of_the_surface() {
+        random::check(CASES, |rng| {
+            let droplet = generate::droplet(rng);
+            let exterior = exterior_surface_area(&droplet).unwrap();
+            assert!(0 < exterior && exterior <= surface_area(&droplet));
+        });
+    }
+}
@@ -0,0 +1,34 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+use crate::Blueprint;
+
+pub fn blueprint(rng: &mut Rng) -> Blueprint {
+    let mut ore = || rng.range(2..=4);
+    let (ore_robot, clay_robot, obsidian_robot, geode_robot) = (ore(), ore(), ore(), ore());
+
+    [
+        [ore_robot, 0, 0, 0],
+        [clay_robot, 0, 0, 0],
+        [obsidian_robot, rng.range(4..=16), 0, 0],
+        [geode_robot, 0, rng.range(4..=16), 0],
+    ]
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    (1..=rng.range(1..=3))
+        .map(|id| {
+            let bp = blueprint(rng);
+            format!(
+                concat!(
+                    "Blueprint {}: Each ore robot costs {} ore. ",
+                    "Each clay robot costs {} ore. ",
+                    "Each obsidian robot costs {} ore and {} clay. ",
+                    "Each geode robot costs {} ore and {} obsidian.\n"
+                ),
+                id, bp[0][0], bp[1][0], bp[2][0], bp[2][1], bp[3][0], bp[3][2]
+            )
+        })
+        .collect()
+}
@@ -0,0 +1,171 @@
+use std::array;
+
+use advent::{
+    params::{Overrides, WithParams},
+    parsers::parse_all,
+    solution::Solution,
+    Result,
+};
+#[allow(unused_imports)]
+use dbg_pls::pretty;
+pub use parser::{parse_blueprint, parse_blueprints, Blueprint, Materials};
+
+pub mod generate;
+mod parser;
+
+fn optimistic_estimate(materials: &Materials, robots: &Materials, mins: u32) -> u32 {
+    let curr_geo = get!(geo materials);
+    let curr_geobots = get!(geo robots) * mins;
+    let new_geobots = (1 + mins) * mins / 2;
+
+    curr_geo + curr_geobots + new_geobots
+}
+
+fn simulate_blueprint_rec(
+    mins: u32,
+    bp: &Blueprint,
+    materials: Materials,
+    robots: Materials,
+    mut best_solution: u32,
+) -> u32 {
+    if mins == 0 {
+        return get!(geo materials);
+    }
+
+    if optimistic_estimate(&materials, &robots, mins) <= best_solution {
+        return u32::MIN;
+    }
+
+    let max_robots: Materials = array::from_fn(|i| bp.iter().map(|r| r[i]).max().unwrap());
+    best_solution = best_solution.max(get!(geo materials) + mins * get!(geo robots));
+
+    'next_recipe: for (robot_idx, recipe) in bp.iter().enumerate().rev() {
+        if robot_idx < 3 && robots[robot_idx] >= max_robots[robot_idx] {
+            continue;
+        }
+
+        let mut time_to_collect = 0;
+        for (material_idx, &count) in recipe.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+
+            if robots[material_idx] == 0 {
+                continue 'next_recipe;
+            }
+
+            let needed = count.saturating_sub(materials[material_idx]);
+            time_to_collect = time_to_collect.max(needed.div_ceil(robots[material_idx]));
+        }
+
+        if time_to_collect + 1 > mins {
+            continue;
+        }
+
+        let new_materials: Materials =
+            array::from_fn(|i| materials[i] + (time_to_collect + 1) * robots[i] - recipe[i]);
+        let mut new_robots = robots;
+        new_robots[robot_idx] += 1;
+
+        best_solution = best_solution.max(simulate_blueprint_rec(
+            mins - time_to_collect - 1,
+            bp,
+            new_materials,
+            new_robots,
+            best_solution,
+        ));
+    }
+
+    best_solution
+}
+
+/// The most geodes opened in `mins` minutes, starting with one ore robot.
+pub fn simulate_blueprint(bp: &Blueprint, mins: u32) -> u32 {
+    simulate_blueprint_rec(mins, bp, [0; 4], [1, 0, 0, 0], u32::MIN)
+}
+
+advent::params! {
+    pub struct Params in "day19" {
+        /// The minutes to crack geodes in part 1.
+        minutes: u32 = 24,
+        /// The minutes to crack geodes in part 2.
+        part2_minutes: u32 = 32,
+        /// How many blueprints are left after the elephants ate the rest,
+        /// in part 2.
+        part2_blueprints: usize = 3,
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = WithParams<Vec<Blueprint>, Params>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        Ok(WithParams {
+            input: parse_all(parse_blueprints, input)?,
+            params: overrides.params()?,
+        })
+    }
+
+    fn part1(blueprints: &Self::Input) -> Result<usize> {
+        let minutes = blueprints.params.minutes;
+        Ok(blueprints
+            .iter()
+            .enumerate()
+            .map(|(idx, bp)| (idx + 1) * simulate_blueprint(bp, minutes) as usize)
+            .sum::<usize>())
+    }
+
+    fn part2(blueprints: &Self::Input) -> Result<usize> {
+        let minutes = blueprints.params.part2_minutes;
+        Ok(blueprints
+            .iter()
+            .take(blueprints.params.part2_blueprints)
+            .map(|bp| simulate_blueprint(bp, minutes) as usize)
+            .product())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, simulate_blueprint, Day19};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day19::parse(INPUT).and_then(|i| Day19::part1(&i)), Ok(33));
+    }
+
+    /// Like with the example, part 2 is too slow for unoptimized tests.
+    #[test]
+    fn generated_inputs() {
+        random::check(CASES / 8, |rng| {
+            let input = generate::input(rng);
+            if let Err(err) = Day19::parse(&input).and_then(|i| Day19::part1(&i)) {
+                panic!("{} for the input:\n{}", err, input);
+            }
+        });
+    }
+
+    #[test]
+    fn more_time_never_hurts() {
+        random::check(CASES / 8, |rng| {
+            let bp = generate::blueprint(rng);
+            let geodes: Vec<_> = (0..=20).map(|mins| simulate_blueprint(&bp, mins)).collect();
+            assert!(geodes.windows(2).all(|w| w[0] <= w[1]), "{:?}", geodes);
+        });
+    }
+}
@@ -1,13 +1,16 @@
+use advent::parsers::parse_unsigned;
 use nom::{
     bytes::complete::{tag, take_while1},
     character::complete::{char, digit1},
-    combinator::{map, map_res},
+    combinator::map,
     multi::separated_list1,
     sequence::{delimited, preceded, separated_pair, tuple},
     IResult,
 };
 
+/// A count of ore, clay, obsidian and geodes, in that order.
 pub type Materials = [u32; 4];
+/// The cost of each robot, in the same order as the materials they collect.
 pub type Blueprint = [Materials; 4];
 
 #[macro_export]
@@ -40,17 +43,17 @@ pub fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
             tuple((
                 preceded(
                     ws,
-                    delimited(tag("Each ore robot costs "), num, tag(" ore.")),
+                    delimited(tag("Each ore robot costs "), parse_unsigned, tag(" ore.")),
                 ),
                 preceded(
                     ws,
-                    delimited(tag("Each clay robot costs "), num, tag(" ore.")),
+                    delimited(tag("Each clay robot costs "), parse_unsigned, tag(" ore.")),
                 ),
                 preceded(
                     ws,
                     delimited(
                         tag("Each obsidian robot costs "),
-                        separated_pair(num, tag(" ore and "), num),
+                        separated_pair(parse_unsigned, tag(" ore and "), parse_unsigned),
                         tag(" clay."),
                     ),
                 ),
@@ -58,7 +61,7 @@ pub fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
                     ws,
                     delimited(
                         tag("Each geode robot costs "),
-                        separated_pair(num, tag(" ore and "), num),
+                        separated_pair(parse_unsigned, tag(" ore and "), parse_unsigned),
                         tag(" obsidian."),
                     ),
                 ),
@@ -78,7 +81,3 @@ pub fn parse_blueprint(input: &str) -> IResult<&str, Blueprint> {
 pub fn ws(input: &str) -> IResult<&str, &str> {
     take_while1(char::is_whitespace)(input)
 }
-
-pub fn num(input: &str) -> IResult<&str, u32> {
-    map_res(digit1, |s: &str| s.parse::<u32>())(input)
-}
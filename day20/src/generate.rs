@@ -0,0 +1,20 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+/// Numbers with repeats, and a single 0.
+pub fn numbers(rng: &mut Rng) -> Vec<isize> {
+    let mut numbers: Vec<isize> = (0..rng.range(1..=30))
+        .map(|_| match rng.range(-30..=30) {
+            0 => 1,
+            n => n,
+        })
+        .collect();
+    numbers.push(0);
+    rng.shuffle(&mut numbers);
+    numbers
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    numbers(rng).iter().map(|n| format!("{}\n", n)).collect()
+}
@@ -0,0 +1,233 @@
+use std::fmt::Display;
+
+use advent::{
+    ensure, invalid,
+    params::{Overrides, WithParams},
+    parsers::{parse_lines, parse_signed},
+    solution::Solution,
+    Result,
+};
+#[allow(unused_imports)]
+use dbg_pls::pretty;
+
+pub mod generate;
+
+/// The encrypted file, with each number's current position (`fwd`) and the
+/// number at each position (`bck`), both by original index.
+#[derive(Clone)]
+pub struct Nums {
+    nums: Vec<isize>,
+    fwd: Vec<usize>,
+    bck: Vec<usize>,
+}
+
+impl Nums {
+    /// Parses one number per line.
+    pub fn new(input: &str) -> Result<Nums> {
+        let nums = parse_lines(parse_signed::<isize>, input)?;
+        ensure!(nums.len() >= 2, "Can't mix fewer than two numbers");
+        let indices: Vec<_> = (0..nums.len()).collect();
+
+        Ok(Nums {
+            nums,
+            fwd: indices.clone(),
+            bck: indices,
+        })
+    }
+
+    fn moved(&self, idx: usize, offset: isize) -> usize {
+        (idx as isize + offset - 1).rem_euclid(self.nums.len() as isize - 1) as usize + 1
+    }
+
+    fn mix_element(&mut self, idx: usize) {
+        let num = self.nums[idx];
+        let original_idx = self.fwd[idx];
+        let new_idx = self.moved(original_idx, num);
+
+        if new_idx == original_idx {
+            return;
+        }
+
+        let popped_idx = self.bck.remove(original_idx);
+        assert_eq!(popped_idx, idx);
+        self.bck.insert(new_idx, idx);
+
+        self.fwd[idx] = new_idx;
+        if original_idx < new_idx {
+            for &i in self.bck[original_idx..new_idx].iter() {
+                assert_ne!(i, idx);
+                self.fwd[i] -= 1;
+            }
+        } else if original_idx > new_idx {
+            for &i in self.bck[(new_idx + 1)..(original_idx + 1)].iter() {
+                assert_ne!(i, idx);
+                self.fwd[i] += 1;
+            }
+        } else {
+            unreachable!()
+        }
+    }
+
+    /// Multiplies every number by `factor`, keeping their order.
+    pub fn multiply(&mut self, factor: isize) {
+        for num in self.nums.iter_mut() {
+            *num *= factor;
+        }
+    }
+
+    /// Moves every number once, in their original order.
+    pub fn mix(&mut self) {
+        for idx in 0..self.nums.len() {
+            self.mix_element(idx);
+        }
+    }
+
+    #[allow(dead_code)]
+    fn check_integrity(&self) {
+        for i in 0..self.nums.len() {
+            assert_eq!(
+                self.bck[self.fwd[i]], i,
+                "self.bck[self.fwd[i = {}] = {}] = {} != {}",
+                i, self.fwd[i], self.bck[self.fwd[i]], i
+            )
+        }
+    }
+
+    /// The number at position `idx`, wrapping around.
+    pub fn get(&self, idx: usize) -> isize {
+        self.nums[self.bck[idx % self.nums.len()]]
+    }
+
+    /// The sum of the numbers 1000, 2000 and 3000 positions after the 0.
+    pub fn calc_coord(&self) -> Result<isize> {
+        let original_zero_idx = self
+            .nums
+            .iter()
+            .position(|&n| n == 0)
+            .ok_or_else(|| invalid!("There is no 0 in the file"))?;
+        let actual_zero_idx = self.fwd[original_zero_idx];
+
+        Ok(self.get(actual_zero_idx + 1000)
+            + self.get(actual_zero_idx + 2000)
+            + self.get(actual_zero_idx + 3000))
+    }
+}
+
+impl Display for Nums {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nums: Vec<_> = self.bck.iter().map(|&i| self.nums[i]).collect();
+        write!(f, "{}", pretty(&nums))?;
+
+        Ok(())
+    }
+}
+
+advent::params! {
+    pub struct Params in "day20" {
+        /// What every number is multiplied by in part 2.
+        decryption_key: isize = 811589153,
+        /// How many times the numbers are mixed in part 2.
+        part2_rounds: usize = 10,
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = WithParams<Nums, Params>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        Ok(WithParams {
+            input: Nums::new(input)?,
+            params: overrides.params()?,
+        })
+    }
+
+    fn part1(nums: &Self::Input) -> Result<isize> {
+        let mut nums = nums.input.clone();
+        nums.mix();
+        nums.calc_coord()
+    }
+
+    fn part2(nums: &Self::Input) -> Result<isize> {
+        let Params {
+            decryption_key,
+            part2_rounds,
+        } = nums.params;
+
+        let mut nums = nums.input.clone();
+        nums.multiply(decryption_key);
+        for _ in 0..part2_rounds {
+            nums.mix();
+        }
+
+        nums.calc_coord()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day20, Nums};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day20::parse(INPUT).and_then(|i| Day20::part1(&i)), Ok(3));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(
+            Day20::parse(INPUT).and_then(|i| Day20::part2(&i)),
+            Ok(1623178306)
+        );
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day20>(CASES, generate::input);
+    }
+
+    #[test]
+    fn mixing_keeps_its_bookkeeping() {
+        random::check(CASES, |rng| {
+            let numbers = generate::numbers(rng);
+            let input: String = numbers.iter().map(|n| format!("{}\n", n)).collect();
+            let mut nums = Nums::new(&input).unwrap();
+
+            // Moving the numbers one by one in a plain list, which is slow
+            // but obviously right.
+            let mut order: Vec<usize> = (0..numbers.len()).collect();
+            let len = numbers.len() as isize;
+            for (idx, &num) in numbers.iter().enumerate() {
+                nums.mix_element(idx);
+                nums.check_integrity();
+
+                let from = order.iter().position(|&i| i == idx).unwrap();
+                order.remove(from);
+                order.insert((from as isize + num).rem_euclid(len - 1) as usize, idx);
+            }
+
+            let zero = numbers.iter().position(|&n| n == 0).unwrap();
+            let plain = order.iter().position(|&i| i == zero).unwrap();
+            for offset in 0..numbers.len() {
+                assert_eq!(
+                    nums.get(nums.fwd[zero] + offset),
+                    numbers[order[(plain + offset) % numbers.len()]]
+                );
+            }
+        });
+    }
+}
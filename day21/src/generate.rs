@@ -0,0 +1,136 @@
+//! Random puzzle inputs.
+
+use std::collections::HashSet;
+
+use advent::random::Rng;
+
+use crate::{Job, Monkey, Operation};
+
+/// The largest number yelled, far from overflowing.
+const MAX_VALUE: i64 = 1 << 40;
+
+struct Tree<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    monkeys: Vec<Monkey>,
+}
+
+impl Tree<'_> {
+    fn name(&mut self) -> String {
+        loop {
+            let name: String = (0..4)
+                .map(|_| self.rng.range(b'a'..=b'z') as char)
+                .collect();
+            if name != "root" && name != "humn" && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    fn add(&mut self, name: String, job: Job) -> String {
+        self.monkeys.push(Monkey {
+            name: name.clone(),
+            job,
+        });
+        name
+    }
+
+    /// The operations that give an integer from `a` and `b`, and that can be
+    /// undone to find either side from the result and the other side.
+    fn operation(&mut self, a: i64, b: i64) -> (Operation, i64) {
+        let mut ops = vec![(Operation::Plus, a + b), (Operation::Minus, a - b)];
+        if let Some(product) = a.checked_mul(b).filter(|p| *p != 0 && p.abs() <= MAX_VALUE) {
+            ops.push((Operation::Times, product));
+        }
+        if b != 0 && a % b == 0 && a / b != 0 {
+            ops.push((Operation::Div, a / b));
+        }
+
+        *self.rng.choose(&ops)
+    }
+
+    /// A monkey at most `depth` levels above the numbers, with the human
+    /// somewhere below it if `human` is given. Returns its name and number.
+    fn monkey(&mut self, depth: usize, human: Option<i64>) -> (String, i64) {
+        if depth == 0 || self.rng.chance(0.25) {
+            return match human {
+                Some(value) => (self.add("humn".to_string(), Job::Number(value)), value),
+                None => {
+                    let value = self.rng.range(1..=20);
+                    let name = self.name();
+                    (self.add(name, Job::Number(value)), value)
+                }
+            };
+        }
+
+        let human_first = self.rng.chance(0.5);
+        let (first, a) = self.monkey(depth - 1, human.filter(|_| human_first));
+        let (second, b) = self.monkey(depth - 1, human.filter(|_| !human_first));
+        let (op, value) = self.operation(a, b);
+
+        let name = self.name();
+        (self.add(name, Job::Operation(first, op, second)), value)
+    }
+}
+
+/// A tree of monkeys whose root's two sides yell the same number, given the
+/// human's number, which is returned with them.
+pub fn monkeys(rng: &mut Rng) -> (Vec<Monkey>, i64) {
+    let human = rng.range(1..=100);
+    let depth = rng.range(1..=6);
+    let mut tree = Tree {
+        rng,
+        names: HashSet::new(),
+        monkeys: Vec::new(),
+    };
+
+    let (with_human, a) = tree.monkey(depth, Some(human));
+    let (without_human, b) = tree.monkey(depth, None);
+
+    // Makes up for the difference, since numbers can't be negative.
+    let (name, op) = (
+        tree.name(),
+        if a >= b {
+            Operation::Plus
+        } else {
+            Operation::Minus
+        },
+    );
+    let difference = tree.add(name, Job::Number((a - b).abs()));
+    let name = tree.name();
+    let balanced = tree.add(name, Job::Operation(without_human, op, difference));
+
+    let (op, _) = tree.operation(a, a);
+    let (left, right) = match tree.rng.chance(0.5) {
+        true => (with_human, balanced),
+        false => (balanced, with_human),
+    };
+    tree.add("root".to_string(), Job::Operation(left, op, right));
+
+    let mut monkeys = tree.monkeys;
+    rng.shuffle(&mut monkeys);
+    (monkeys, human)
+}
+
+/// The puzzle's listing of every monkey's job.
+pub fn jobs(monkeys: &[Monkey]) -> String {
+    monkeys
+        .iter()
+        .map(|monkey| match &monkey.job {
+            Job::Number(n) => format!("{}: {}\n", monkey.name, n),
+            Job::Operation(a, op, b) => {
+                let op = match op {
+                    Operation::Plus => '+',
+                    Operation::Minus => '-',
+                    Operation::Times => '*',
+                    Operation::Div => '/',
+                };
+                format!("{}: {} {} {}\n", monkey.name, a, op, b)
+            }
+        })
+        .collect()
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    jobs(&monkeys(rng).0)
+}
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+
+use advent::{
+    debug, ensure, invalid,
+    params::{Overrides, WithParams},
+    parsers::parse_all,
+    solution::Solution,
+    trace, Error, Result,
+};
+use dbg_pls::pretty;
+
+pub use parser::{parse_monkeys, Job, Monkey, Operation};
+
+pub mod generate;
+mod parser;
+
+/// Each monkey's job, by name.
+pub fn monkeys(input: &str) -> Result<HashMap<String, Job>> {
+    let monkeys = parse_all(parse_monkeys, input)?;
+
+    Ok(monkeys
+        .into_iter()
+        .map(|monkey| (monkey.name, monkey.job))
+        .collect())
+}
+
+fn job<'a>(monkeys: &'a HashMap<String, Job>, monkey_name: &str) -> Result<&'a Job> {
+    monkeys
+        .get(monkey_name)
+        .ok_or_else(|| invalid!("Unknown monkey {}", monkey_name))
+}
+
+/// The number yelled by `monkey_name`, remembering every number found in
+/// `memo`. It's `None` if the number depends on `ignored_human`.
+pub fn eval_rec(
+    monkey_name: &str,
+    monkeys: &HashMap<String, Job>,
+    memo: &mut HashMap<String, i64>,
+    ignored_human: Option<&str>,
+) -> Result<Option<i64>> {
+    if ignored_human == Some(monkey_name) {
+        return Ok(None);
+    }
+
+    if let Some(&val) = memo.get(monkey_name) {
+        return Ok(Some(val));
+    }
+
+    let val = match job(monkeys, monkey_name)? {
+        Job::Number(num) => *num,
+        Job::Operation(monkey1, op, monkey2) => {
+            let val1 = eval_rec(monkey1, monkeys, memo, ignored_human)?;
+            let val2 = eval_rec(monkey2, monkeys, memo, ignored_human)?;
+            let (Some(val1), Some(val2)) = (val1, val2) else {
+                return Ok(None);
+            };
+
+            match op {
+                Operation::Plus => val1 + val2,
+                Operation::Minus => val1 - val2,
+                Operation::Times => val1 * val2,
+                Operation::Div => {
+                    ensure!(
+                        val2 != 0 && val1 % val2 == 0,
+                        "Monkey {} can't divide {} by {}",
+                        monkey_name,
+                        val1,
+                        val2
+                    );
+                    val1 / val2
+                }
+            }
+        }
+    };
+
+    memo.insert(monkey_name.to_string(), val);
+    Ok(Some(val))
+}
+
+/// Divides exactly, since the human has to yell an integer.
+fn exact_div(dividend: i64, divisor: i64) -> Result<i64> {
+    if divisor == 0 || dividend % divisor != 0 {
+        return Err(Error::NoSolution);
+    }
+
+    Ok(dividend / divisor)
+}
+
+/// The number `human` must yell for `monkey_name` to yell `target_value`,
+/// given the numbers of the monkeys that don't depend on it in `memo`.
+pub fn eval_human(
+    monkey_name: &str,
+    human: &str,
+    target_value: i64,
+    monkeys: &HashMap<String, Job>,
+    memo: &HashMap<String, i64>,
+) -> Result<i64> {
+    assert!(
+        !memo.contains_key(monkey_name),
+        "Attempting to evaluate known monkey {}",
+        monkey_name
+    );
+
+    trace!("Attempting to make {} = {}", monkey_name, target_value);
+
+    if monkey_name == human {
+        return Ok(target_value);
+    }
+
+    let Job::Operation(child1, op, child2) = job(monkeys, monkey_name)? else {
+        unreachable!("Number monkeys are always known")
+    };
+
+    let (child_name, child_target) = if let Some(&val) = memo.get(child1) {
+        (
+            child2,
+            match op {
+                Operation::Plus => target_value - val,
+                Operation::Minus => val - target_value,
+                Operation::Times => exact_div(target_value, val)?,
+                Operation::Div => exact_div(val, target_value)?,
+            },
+        )
+    } else {
+        let val = *memo
+            .get(child2)
+            .ok_or_else(|| invalid!("Both sides of {} depend on {}", monkey_name, human))?;
+        (
+            child1,
+            match op {
+                Operation::Plus => target_value - val,
+                Operation::Minus => target_value + val,
+                Operation::Times => exact_div(target_value, val)?,
+                Operation::Div => target_value * val,
+            },
+        )
+    };
+
+    eval_human(child_name, human, child_target, monkeys, memo)
+}
+
+advent::params! {
+    pub struct Params in "day21" {
+        /// The monkey whose number is asked for.
+        root: String = "root".to_string(),
+        /// The name of the human among the monkeys, in part 2.
+        human: String = "humn".to_string(),
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = WithParams<HashMap<String, Job>, Params>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Self::parse_with(input, &Overrides::default())
+    }
+
+    fn parse_with(input: &str, overrides: &Overrides) -> Result<Self::Input> {
+        Ok(WithParams {
+            input: monkeys(input)?,
+            params: overrides.params()?,
+        })
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<i64> {
+        let mut memo = HashMap::new();
+        eval_rec(&monkeys.params.root, monkeys, &mut memo, None)?.ok_or(Error::NoSolution)
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<i64> {
+        let Params { root, human } = &monkeys.params;
+
+        let mut memo = HashMap::new();
+        eval_rec(root, monkeys, &mut memo, Some(human))?;
+
+        let Job::Operation(child1, _, child2) = job(monkeys, root)? else {
+            return Err(invalid!("{} is a leaf", root));
+        };
+
+        let (search_monkey, target_value) = match (memo.get(child1), memo.get(child2)) {
+            (Some(_), Some(_)) => return Err(invalid!("{} doesn't matter to {}", human, root)),
+            (Some(&val), None) => (child2, val),
+            (None, Some(&val)) => (child1, val),
+            (None, None) => {
+                return Err(invalid!("Both sides of {} depend on {}", root, human));
+            }
+        };
+
+        debug!("Known monkeys: {}", pretty(&memo));
+        eval_human(search_monkey, human, target_value, monkeys, &memo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day21};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day21::parse(INPUT).and_then(|i| Day21::part1(&i)), Ok(152));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day21::parse(INPUT).and_then(|i| Day21::part2(&i)), Ok(301));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day21>(CASES, generate::input);
+    }
+
+    #[test]
+    fn finds_the_human_number() {
+        random::check(CASES, |rng| {
+            let (monkeys, human) = generate::monkeys(rng);
+            assert_eq!(
+                Day21::parse(&generate::jobs(&monkeys)).and_then(|i| Day21::part2(&i)),
+                Ok(human)
+            );
+        });
+    }
+}
@@ -1,9 +1,10 @@
+use advent::parsers::parse_unsigned;
 use dbg_pls::DebugPls;
 use nom::{
     branch::alt,
     bytes::complete::{tag, take_while_m_n},
-    character::complete::{char, digit1, line_ending, space0},
-    combinator::{map, map_res, value},
+    character::complete::{char, line_ending, space0},
+    combinator::{map, value},
     multi::separated_list1,
     sequence::{delimited, separated_pair, tuple},
     IResult,
@@ -43,7 +44,6 @@ fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
         )
     };
 
-    let num = || map_res(digit1, |s: &str| s.parse::<i64>());
     let op = alt((
         value(Operation::Plus, char('+')),
         value(Operation::Minus, char('-')),
@@ -52,7 +52,7 @@ fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
     ));
 
     let job = alt((
-        map(num(), Job::Number),
+        map(parse_unsigned, Job::Number),
         map(
             tuple((monkey_name(), stripped(op), monkey_name())),
             |(monkey1, op, monkey2)| Job::Operation(monkey1, op, monkey2),
@@ -0,0 +1,147 @@
+//! Random puzzle inputs.
+
+use std::collections::HashMap;
+
+use advent::{coord::Coord, coord3::Coord3, direction::Direction, random::Rng};
+
+/// Where a face of the cube lies: its outward normal, and the directions
+/// of the net's right and down on it.
+#[derive(Clone, Copy)]
+struct Orientation {
+    normal: Coord3,
+    right: Coord3,
+    down: Coord3,
+}
+
+impl Orientation {
+    /// The face reached by rolling the cube over its edge in `dir`.
+    fn roll(self, dir: Direction) -> Orientation {
+        let Orientation {
+            normal,
+            right,
+            down,
+        } = self;
+
+        match dir {
+            Direction::East => Orientation {
+                normal: right,
+                right: -normal,
+                down,
+            },
+            Direction::West => Orientation {
+                normal: -right,
+                right: normal,
+                down,
+            },
+            Direction::South => Orientation {
+                normal: down,
+                right,
+                down: -normal,
+            },
+            Direction::North => Orientation {
+                normal: -down,
+                right,
+                down: normal,
+            },
+        }
+    }
+}
+
+/// The faces of a random cube net, in units of faces. Unfolding the cube
+/// face after face makes one of the eleven nets, in any orientation.
+pub fn net(rng: &mut Rng) -> Vec<Coord> {
+    'retry: loop {
+        let mut faces = HashMap::from([(
+            Coord::zero(),
+            Orientation {
+                normal: Coord3::new(0, 0, 1),
+                right: Coord3::new(1, 0, 0),
+                down: Coord3::new(0, 1, 0),
+            },
+        )]);
+
+        while faces.len() < 6 {
+            let mut unfoldings: Vec<(Coord, Orientation)> = faces
+                .iter()
+                .flat_map(|(&pos, &face)| {
+                    Direction::ALL.map(|dir| (pos + dir.step(), face.roll(dir)))
+                })
+                .filter(|(pos, face)| {
+                    // A face next to two others would close a loop, which no
+                    // net has.
+                    let next_to = pos
+                        .orthogonal_neighbours()
+                        .filter(|n| faces.contains_key(n))
+                        .count();
+                    next_to == 1 && faces.values().all(|f| f.normal != face.normal)
+                })
+                .collect();
+
+            // The map's iteration order isn't seeded.
+            unfoldings.sort_by_key(|(pos, _)| (pos.y, pos.x));
+            if unfoldings.is_empty() {
+                continue 'retry;
+            }
+
+            let (pos, face) = *rng.choose(&unfoldings);
+            faces.insert(pos, face);
+        }
+
+        let min = faces.keys().fold(Coord::zero(), |min, &pos| min.min(pos));
+        let mut net: Vec<Coord> = faces.keys().map(|&pos| pos - min).collect();
+        net.sort_by_key(|pos| (pos.y, pos.x));
+        return net;
+    }
+}
+
+/// The board made of the faces of `net`, each `face_len` tiles wide, with
+/// a wall on each tile with probability `walls`. The first tile is open.
+pub fn board(rng: &mut Rng, net: &[Coord], face_len: usize, walls: f64) -> String {
+    let width = net.iter().map(|pos| pos.x + 1).max().unwrap_or(0) as usize * face_len;
+    let height = net.iter().map(|pos| pos.y + 1).max().unwrap_or(0) as usize * face_len;
+    let is_on_net = |x: usize, y: usize| {
+        net.contains(&Coord::new(
+            (x / face_len) as isize,
+            (y / face_len) as isize,
+        ))
+    };
+
+    let mut first = true;
+    let rows: Vec<String> = (0..height)
+        .map(|y| {
+            let row: String = (0..width)
+                .map(|x| match is_on_net(x, y) {
+                    false => ' ',
+                    true if std::mem::take(&mut first) => '.',
+                    true if rng.chance(walls) => '#',
+                    true => '.',
+                })
+                .collect();
+            row.trim_end().to_string()
+        })
+        .collect();
+
+    rows.join("\n")
+}
+
+/// Step counts between random turns.
+pub fn path(rng: &mut Rng, face_len: usize) -> String {
+    let mut path = rng.range(0..=4 * face_len).to_string();
+    for _ in 0..rng.range(0..=12) {
+        path.push(*rng.choose(&['L', 'R']));
+        path += &rng.range(0..=4 * face_len).to_string();
+    }
+
+    path
+}
+
+pub fn input(rng: &mut Rng) -> String {
+    let face_len = rng.range(1..=5);
+    let net = net(rng);
+
+    format!(
+        "{}\n\n{}\n",
+        board(rng, &net, face_len, 0.15),
+        path(rng, face_len)
+    )
+}
@@ -0,0 +1,255 @@
+use advent::{
+    coord::Coord,
+    direction::Direction,
+    grid::Grid,
+    image::{self, Picture, Rgb},
+    invalid,
+    parsers::{parse_all, parse_unsigned, ParseError},
+    solution::Solution,
+    Result,
+};
+#[allow(unused_imports)]
+use dbg_pls::pretty;
+use nom::{
+    bytes::complete::take_until,
+    character::complete::{char, multispace1},
+    error::ErrorKind,
+    multi::separated_list1,
+    sequence::{pair, preceded},
+};
+
+pub use part2::cube_password;
+
+pub mod generate;
+mod part2;
+
+/// The step counts of the instructions, split at every `R` and then at
+/// every `L`.
+fn parse_instruction_line(input: &str) -> Result<Vec<Vec<usize>>> {
+    let map = pair(take_until("\n\n"), multispace1);
+    let left_turns = separated_list1(char('L'), parse_unsigned);
+    let instructions = separated_list1(char('R'), left_turns);
+
+    Ok(parse_all(preceded(map, instructions), input)?)
+}
+
+/// A tile of the board, or the space around it.
+#[derive(Clone, Copy, PartialEq, Eq, dbg_pls::DebugPls, Debug)]
+pub enum Field {
+    OutOfMap,
+    Empty,
+    Wall,
+}
+
+fn make_grid(input: &str) -> Result<Grid<Field>> {
+    let (grid_input, _) = input.split_once("\n\n").ok_or_else(|| {
+        invalid!("The map and the instructions should be separated by an empty line")
+    })?;
+
+    if let Some(offset) = grid_input.find(|c| !" .#\n".contains(c)) {
+        return Err(ParseError::new(input, &input[offset..], ErrorKind::Char).into());
+    }
+
+    Ok(Grid::from_chars_padded(
+        grid_input,
+        Field::OutOfMap,
+        |field| match field {
+            '.' => Field::Empty,
+            '#' => Field::Wall,
+            _ => Field::OutOfMap,
+        },
+    ))
+}
+
+fn calc_coordinate(pos: Coord, dir: Direction) -> isize {
+    (pos.y + 1) * 1000
+        + (pos.x + 1) * 4
+        + match dir {
+            Direction::North => 3,
+            Direction::South => 1,
+            Direction::West => 2,
+            Direction::East => 0,
+        }
+}
+
+const OPEN: Rgb = Rgb(235, 230, 215);
+const WALL: Rgb = Rgb(70, 70, 80);
+const PATH: Rgb = Rgb(40, 120, 220);
+const START: Rgb = Rgb(40, 170, 70);
+const END: Rgb = Rgb(220, 40, 40);
+
+/// The board with the walked path. The path is split where it wraps
+/// around, so that no line crosses the board.
+fn picture(grid: &Grid<Field>, path: &[Coord]) -> Picture {
+    let mut picture = Picture::from_grid(grid, |_, field| match field {
+        Field::OutOfMap => None,
+        Field::Empty => Some(OPEN),
+        Field::Wall => Some(WALL),
+    });
+
+    let mut rest = path;
+    while !rest.is_empty() {
+        let len = rest
+            .windows(2)
+            .position(|pair| (pair[1] - pair[0]).manhattan() > 1)
+            .map_or(rest.len(), |idx| idx + 1);
+        picture = picture.path(rest[..len].iter().copied(), PATH);
+        rest = &rest[len..];
+    }
+
+    if let (Some(&start), Some(&end)) = (path.first(), path.last()) {
+        picture = picture.marker(start, START).marker(end, END);
+    }
+
+    picture
+        .legend("Open tile", OPEN)
+        .legend("Wall", WALL)
+        .legend("Path", PATH)
+        .legend("Start", START)
+        .legend("End", END)
+}
+
+/// The map of the board, and the path to follow on it.
+pub struct Notes {
+    pub grid: Grid<Field>,
+    /// The step counts, split at every `R` and then at every `L`.
+    pub instructions: Vec<Vec<usize>>,
+}
+
+/// The password found by following the path, wrapping around to the other
+/// side of the board when leaving it.
+pub fn board_password(notes: &Notes) -> Result<isize> {
+    let Notes { grid, instructions } = notes;
+
+    let (mut curr_pos, _) = grid
+        .row(0)
+        .find(|&(_, &f)| f != Field::OutOfMap)
+        .ok_or_else(|| invalid!("The first row of the map is empty"))?;
+    let mut curr_dir = Direction::East;
+    let mut path = vec![curr_pos];
+
+    for (right_step_idx, right_steps) in instructions.iter().enumerate() {
+        if right_step_idx != 0 {
+            curr_dir = curr_dir.clockwise();
+        }
+
+        for (left_step_idx, &left_steps) in right_steps.iter().enumerate() {
+            if left_step_idx != 0 {
+                curr_dir = curr_dir.anticlockwise();
+            }
+
+            for _ in 0..left_steps {
+                let next_pos = curr_pos + curr_dir.step();
+                let next_field = grid.get(next_pos).copied().unwrap_or(Field::OutOfMap);
+
+                curr_pos = match next_field {
+                    Field::OutOfMap => {
+                        let opp_dir = curr_dir.opposite();
+                        let mut pos = curr_pos;
+                        while grid
+                            .get(pos + opp_dir.step())
+                            .is_some_and(|&f| f != Field::OutOfMap)
+                        {
+                            pos += opp_dir.step();
+                        }
+
+                        match grid[pos] {
+                            Field::OutOfMap => unreachable!(),
+                            Field::Empty => pos,
+                            Field::Wall => break,
+                        }
+                    }
+                    Field::Empty => curr_pos + curr_dir.step(),
+                    Field::Wall => break,
+                };
+                path.push(curr_pos);
+            }
+        }
+    }
+
+    image::save("day22-part1", || picture(grid, &path));
+    Ok(calc_coordinate(curr_pos, curr_dir))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Notes;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<Notes> {
+        Ok(Notes {
+            grid: make_grid(input)?,
+            instructions: parse_instruction_line(input)?,
+        })
+    }
+
+    fn part1(notes: &Notes) -> Result<isize> {
+        board_password(notes)
+    }
+
+    fn part2(notes: &Notes) -> Result<isize> {
+        cube_password(notes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, Day22};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day22::parse(INPUT).and_then(|i| Day22::part1(&i)), Ok(6032));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day22::parse(INPUT).and_then(|i| Day22::part2(&i)), Ok(5031));
+    }
+
+    #[test]
+    fn unfoldable_nets() {
+        for board in ["......", "...\n..."] {
+            let input = format!("{}\n\n1\n", board);
+            assert!(Day22::parse(&input).and_then(|i| Day22::part2(&i)).is_err());
+        }
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day22>(CASES, generate::input);
+    }
+
+    #[test]
+    fn going_around_the_cube() {
+        random::check(CASES, |rng| {
+            let face_len = rng.range(1..=5);
+            let net = generate::net(rng);
+            let board = generate::board(rng, &net, face_len, 0.);
+
+            // Without walls, walking four faces straight ahead comes back to
+            // the same tile, facing the same way.
+            let turns: Vec<char> = (0..rng.range(0..=6))
+                .map(|_| *rng.choose(&['L', 'R']))
+                .collect();
+            let path = |steps: usize| {
+                turns.iter().fold(steps.to_string(), |path, turn| {
+                    format!("{}{}{}", path, turn, steps)
+                })
+            };
+
+            let password = |path: String| {
+                Day22::parse(&format!("{}\n\n{}\n", board, path)).and_then(|i| Day22::part2(&i))
+            };
+            assert_eq!(password(path(4 * face_len)), password(path(0)));
+        });
+    }
+}
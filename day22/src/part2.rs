@@ -1,6 +1,13 @@
-use advent::coord::{ContainsCoord, Coord};
+use advent::{
+    coord::{ContainsCoord, Coord},
+    debug,
+    direction::Direction,
+    ensure,
+    grid::Grid,
+    image, invalid, trace, Result,
+};
 
-use crate::{Direction, Field};
+use crate::{picture, Field, Notes};
 
 const CUBE_FACES: usize = 6;
 
@@ -30,87 +37,82 @@ impl Face {
     }
 }
 
+type CubeMaps = (
+    Grid<Option<usize>>,
+    [(usize, usize); CUBE_FACES],
+    [Grid<Field>; CUBE_FACES],
+);
+
 struct Cube {
     face_len: usize,
-    maps: [Vec<Vec<Field>>; CUBE_FACES],
+    maps: [Grid<Field>; CUBE_FACES],
     map_coords: [(usize, usize); CUBE_FACES],
     neighbours: [[usize; 4]; CUBE_FACES],
-    instructions: Vec<Vec<usize>>,
 }
 
 impl Cube {
-    fn new(input: &str) -> Cube {
-        let (grid_lines, instruction_line) = super::split_grid_and_instruction(input);
-
-        let grid = super::make_grid(&grid_lines);
-        let instructions = super::parse_instruction_line(instruction_line);
-        let face_len = Self::face_len(&grid);
+    fn new(grid: &Grid<Field>) -> Result<Cube> {
+        let face_len = Self::face_len(grid)?;
 
-        let (cube_map, map_coords, maps) = Self::build_cube_maps(&grid, face_len);
-        let neighbours = WalkNeighbours::walk(&map_coords, &cube_map);
+        let (cube_map, map_coords, maps) = Self::build_cube_maps(grid, face_len)?;
+        let neighbours = WalkNeighbours::walk(&map_coords, &cube_map)?;
 
-        Cube {
+        Ok(Cube {
             face_len,
             maps,
             map_coords,
             neighbours,
-            instructions,
-        }
+        })
     }
 
-    fn face_len(grid: &Vec<Vec<Field>>) -> usize {
-        let cube_blocks = grid
-            .iter()
-            .map(|l| l.iter().filter(|&&el| el != Field::OutOfMap).count())
-            .sum::<usize>();
+    fn face_len(grid: &Grid<Field>) -> Result<usize> {
+        let cube_blocks = grid.values().filter(|&&el| el != Field::OutOfMap).count();
 
         let face_length_f = ((cube_blocks / CUBE_FACES) as f64).sqrt() + f64::EPSILON;
         let face_length_u = face_length_f as usize;
 
-        assert!((face_length_f - face_length_u as f64).abs() <= f64::EPSILON);
-        face_length_u
+        ensure!(
+            face_length_u > 0 && face_length_u * face_length_u * CUBE_FACES == cube_blocks,
+            "A map of {} tiles can't be folded into a cube",
+            cube_blocks
+        );
+        Ok(face_length_u)
     }
 
-    fn build_cube_maps(
-        grid: &Vec<Vec<Field>>,
-        face_len: usize,
-    ) -> (
-        Vec<Vec<Option<usize>>>,
-        [(usize, usize); CUBE_FACES],
-        [Vec<Vec<Field>>; CUBE_FACES],
-    ) {
-        let cube_map_height = grid.len() / face_len;
-        let cube_map_width = grid.iter().map(|l| l.len()).max().unwrap() / face_len;
-
-        let mut cube_map = vec![vec![None; cube_map_width]; cube_map_height];
+    fn build_cube_maps(grid: &Grid<Field>, face_len: usize) -> Result<CubeMaps> {
+        let cube_map_height = grid.height() / face_len;
+        let cube_map_width = grid.width() / face_len;
+
+        let mut cube_map = Grid::new(cube_map_width, cube_map_height, None);
         let mut maps = Vec::with_capacity(CUBE_FACES);
         let mut coords = Vec::with_capacity(CUBE_FACES);
 
         for y in 0..cube_map_height {
             for x in 0..cube_map_width {
-                let (ox, oy) = (x * face_len, y * face_len);
-                if ox >= grid[oy].len() || grid[oy][ox] == Field::OutOfMap {
+                let origin = Coord::new((x * face_len) as isize, (y * face_len) as isize);
+                if grid[origin] == Field::OutOfMap {
                     continue;
                 }
 
-                cube_map[y][x] = Some(maps.len());
+                cube_map[Coord::new(x as isize, y as isize)] = Some(maps.len());
                 coords.push((y, x));
 
-                maps.push(
-                    grid.iter()
-                        .skip(oy)
-                        .take(face_len)
-                        .map(|l| l.iter().skip(ox).take(face_len).copied().collect())
-                        .collect(),
+                let map = Grid::from_fn(face_len, face_len, |pos| grid[origin + pos]);
+                ensure!(
+                    map.values().all(|&f| f != Field::OutOfMap),
+                    "The face at {} isn't complete",
+                    origin
                 );
+                maps.push(map);
             }
         }
 
-        (
+        let not_a_cube = || invalid!("The map doesn't have {} faces", CUBE_FACES);
+        Ok((
             cube_map,
-            coords.try_into().unwrap(),
-            maps.try_into().unwrap(),
-        )
+            coords.try_into().map_err(|_| not_a_cube())?,
+            maps.try_into().map_err(|_| not_a_cube())?,
+        ))
     }
 
     fn is_in_face(&self, coord: Coord) -> bool {
@@ -127,23 +129,24 @@ impl Cube {
         )
     }
 
-    fn walk(&mut self) -> isize {
+    /// Follows the instructions, adding each position on the map to `path`.
+    fn walk(&self, instructions: &[Vec<usize>], path: &mut Vec<Coord>) -> isize {
         let mut curr_pos = Coord::zero();
         let mut curr_face = 0usize;
         let mut curr_dir = Direction::East;
 
-        for (right_idx, right_steps) in self.instructions.iter().enumerate() {
+        for (right_idx, right_steps) in instructions.iter().enumerate() {
             if right_idx > 0 {
-                curr_dir = curr_dir.turn_right();
+                curr_dir = curr_dir.clockwise();
             }
 
             for (left_idx, &steps) in right_steps.iter().enumerate() {
                 if left_idx > 0 {
-                    curr_dir = curr_dir.turn_left();
+                    curr_dir = curr_dir.anticlockwise();
                 }
 
                 for _ in 0..steps {
-                    eprintln!(
+                    trace!(
                         "{} (face {}, pos {}), direction: {:?}",
                         self.coords(curr_face, curr_pos),
                         curr_face,
@@ -159,6 +162,7 @@ impl Cube {
                             Field::Wall => break,
                         }
 
+                        path.push(self.coords(curr_face, curr_pos));
                         continue;
                     }
 
@@ -167,14 +171,14 @@ impl Cube {
                     let mut next_pos = self.modulo_face(next_pos);
 
                     while self.neighbours[next_face][next_dir.opposite() as usize] != curr_face {
-                        next_dir = next_dir.turn_right();
+                        next_dir = next_dir.clockwise();
                         next_pos = Coord::new(self.face_len as isize - 1 - next_pos.y, next_pos.x)
                     }
 
                     match self.maps[next_face][next_pos] {
                         Field::OutOfMap => unreachable!(),
                         Field::Empty => {
-                            eprintln!(
+                            trace!(
                                 "  moving to {} (face {}, pos {}), direction: {:?}",
                                 self.coords(next_face, next_pos),
                                 next_face,
@@ -185,6 +189,7 @@ impl Cube {
                             curr_pos = next_pos;
                             curr_face = next_face;
                             curr_dir = next_dir;
+                            path.push(self.coords(curr_face, curr_pos));
                         }
                         Field::Wall => break,
                     }
@@ -192,7 +197,7 @@ impl Cube {
             }
         }
 
-        eprintln!(
+        debug!(
             "Curr face: {}, pos: {}, dir: {:?}, coords: {}",
             curr_face,
             curr_pos,
@@ -211,7 +216,7 @@ impl Cube {
 
 struct WalkNeighbours<'a> {
     coords: &'a [(usize, usize); CUBE_FACES],
-    map: &'a Vec<Vec<Option<usize>>>,
+    map: &'a Grid<Option<usize>>,
     neighbours: [[Option<Face>; 4]; CUBE_FACES],
     face_indices: [Option<usize>; CUBE_FACES],
     filled: [bool; CUBE_FACES],
@@ -220,8 +225,8 @@ struct WalkNeighbours<'a> {
 impl<'a> WalkNeighbours<'a> {
     fn walk(
         coords: &'a [(usize, usize); CUBE_FACES],
-        map: &'a Vec<Vec<Option<usize>>>,
-    ) -> [[usize; 4]; CUBE_FACES] {
+        map: &'a Grid<Option<usize>>,
+    ) -> Result<[[usize; 4]; CUBE_FACES]> {
         let mut walk = WalkNeighbours {
             coords,
             map,
@@ -230,20 +235,38 @@ impl<'a> WalkNeighbours<'a> {
             filled: [false; CUBE_FACES],
         };
 
-        walk.rec(0, Face::Top, (Direction::East, Face::Right));
-        walk.neighbours
-            .map(|f| f.map(|n| walk.face_indices[n.unwrap() as usize].unwrap()))
+        walk.rec(0, Face::Top, (Direction::East, Face::Right))?;
+        ensure!(
+            walk.filled.iter().all(|&filled| filled),
+            "The faces of the map aren't connected"
+        );
+
+        let mut neighbours = [[0; 4]; CUBE_FACES];
+        for (face_idx, faces) in walk.neighbours.iter().enumerate() {
+            for (dir, face) in faces.iter().enumerate() {
+                neighbours[face_idx][dir] = face
+                    .and_then(|face| walk.face_indices[face as usize])
+                    .ok_or_else(|| invalid!("The map doesn't fold into a cube"))?;
+            }
+        }
+
+        Ok(neighbours)
     }
 
-    fn rec(&mut self, curr_face_idx: usize, curr_face: Face, known_neighbour: (Direction, Face)) {
+    fn rec(
+        &mut self,
+        curr_face_idx: usize,
+        curr_face: Face,
+        known_neighbour: (Direction, Face),
+    ) -> Result<()> {
         if self.filled[curr_face_idx] {
-            return;
+            return Ok(());
         }
 
-        self.fill_neighbours(curr_face, curr_face_idx, known_neighbour);
+        self.fill_neighbours(curr_face, curr_face_idx, known_neighbour)?;
         self.filled[curr_face_idx] = true;
 
-        let mut walk_in_dir = |direction: Direction| {
+        for direction in Direction::ALL {
             let step = direction.step();
             let curr_coord = Coord::new(
                 self.coords[curr_face_idx].1 as isize,
@@ -252,31 +275,36 @@ impl<'a> WalkNeighbours<'a> {
             let new_coord = curr_coord + step;
 
             if !self.map.contains_coord(new_coord) {
-                return;
+                continue;
             }
 
             let Some(new_face_idx) = self.map[new_coord] else {
-                return;
+                continue;
             };
 
-            let new_face = self.neighbours[curr_face_idx][direction as usize].unwrap();
-            self.rec(new_face_idx, new_face, (direction.opposite(), curr_face));
-        };
-
-        for dir in Direction::CLOCKWISE {
-            walk_in_dir(dir);
+            let new_face = self.neighbours[curr_face_idx][direction as usize]
+                .ok_or_else(|| invalid!("The map doesn't fold into a cube"))?;
+            self.rec(new_face_idx, new_face, (direction.opposite(), curr_face))?;
         }
+
+        Ok(())
     }
 
-    fn fill_neighbours(&mut self, face: Face, face_idx: usize, known_neighbour: (Direction, Face)) {
+    fn fill_neighbours(
+        &mut self,
+        face: Face,
+        face_idx: usize,
+        known_neighbour: (Direction, Face),
+    ) -> Result<()> {
+        // Two faces of the map folding onto the same side of the cube.
+        ensure!(
+            self.face_indices[face as usize].is_none_or(|idx| idx == face_idx),
+            "The map doesn't fold into a cube"
+        );
         self.face_indices[face as usize] = Some(face_idx);
         let (known_dir, known_face) = known_neighbour;
 
-        let dirs = Direction::CLOCKWISE
-            .iter()
-            .cycle()
-            .skip_while(|&&d| d != known_dir)
-            .take(4);
+        let dirs = known_dir.clockwise_from();
 
         let faces = Face::neighbours_clockwise(face)
             .iter()
@@ -284,13 +312,21 @@ impl<'a> WalkNeighbours<'a> {
             .skip_while(|&&f| f != known_face)
             .take(4);
 
-        for (&dir, &face) in dirs.zip(faces) {
+        for (dir, &face) in dirs.zip(faces) {
             self.neighbours[face_idx][dir as usize] = Some(face)
         }
+
+        Ok(())
     }
 }
 
-pub fn part2(input: &str) -> isize {
-    let mut cube = Cube::new(input);
-    cube.walk()
+/// The password found by following the path once the board is folded into
+/// a cube.
+pub fn cube_password(notes: &Notes) -> Result<isize> {
+    let cube = Cube::new(&notes.grid)?;
+    let mut path = vec![cube.coords(0, Coord::zero())];
+    let password = cube.walk(&notes.instructions, &mut path);
+
+    image::save("day22-part2", || picture(&notes.grid, &path));
+    Ok(password)
 }
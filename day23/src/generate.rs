@@ -0,0 +1,18 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+/// A small grove with a few elves, so that they soon stop moving.
+pub fn input(rng: &mut Rng) -> String {
+    let (width, height) = (rng.range(1..=10), rng.range(1..=10));
+    let density = rng.range(1..=6) as f64 / 10.;
+
+    (0..height)
+        .map(|_| {
+            let row: String = (0..width)
+                .map(|_| if rng.chance(density) { '#' } else { '.' })
+                .collect();
+            row + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,209 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::Display,
+    iter::from_fn,
+};
+
+use advent::{
+    coord::Coord,
+    direction::{Compass, Direction},
+    parsers::{parse_all, parse_char_grid},
+    record,
+    solution::Solution,
+    sparse_grid::SparseGrid,
+    step::{Simulation, Steppable},
+    Result,
+};
+
+#[allow(unused_imports)]
+use dbg_pls::pretty;
+
+pub mod generate;
+
+fn move_preferences() -> impl Iterator<Item = impl Iterator<Item = Direction> + Clone> {
+    use Direction::*;
+
+    let mut prefs = [North, South, West, East].into_iter().cycle();
+    from_fn(move || {
+        let it = prefs.clone();
+        prefs.next().unwrap();
+        Some(it.take(4))
+    })
+}
+
+#[derive(dbg_pls::DebugPls)]
+enum Proposition {
+    Single(Coord),
+    Multiple,
+}
+
+fn show_map(map: &SparseGrid<()>) -> impl Display + '_ {
+    map.render(|_, elf| if elf.is_some() { '#' } else { '.' })
+}
+
+/// The elves spreading out, one round at a time.
+pub struct Grove {
+    map: SparseGrid<()>,
+    /// The rounds played so far.
+    round: usize,
+    /// The rounds to play, or `None` to go on until no elf moves.
+    rounds: Option<usize>,
+}
+
+impl Grove {
+    pub fn new(map: &SparseGrid<()>, rounds: Option<usize>) -> Grove {
+        Grove {
+            map: map.clone(),
+            round: 0,
+            rounds,
+        }
+    }
+
+    /// Plays the next round, returning `false` if there's none left, or no
+    /// elf moved.
+    pub fn spread(&mut self) -> bool {
+        if self.rounds.is_some_and(|rounds| self.round >= rounds) {
+            return false;
+        }
+
+        let preferences = move_preferences()
+            .nth(self.round)
+            .expect("The preferences cycle forever");
+        let mut propositions = HashMap::<Coord, Proposition>::new();
+
+        for coord in self.map.coords() {
+            let is_empty = |dir: Compass| !self.map.contains(coord + dir.step());
+
+            if Compass::ALL.into_iter().all(is_empty) {
+                continue;
+            }
+
+            let Some(proposed_dir) = preferences.clone().find(|&dir| {
+                let dir = Compass::from(dir);
+                is_empty(dir) && is_empty(dir.clockwise()) && is_empty(dir.anticlockwise())
+            }) else {
+                continue;
+            };
+
+            match propositions.entry(coord + proposed_dir.step()) {
+                Entry::Vacant(entry) => _ = entry.insert(Proposition::Single(coord)),
+                Entry::Occupied(mut entry) => *entry.get_mut() = Proposition::Multiple,
+            };
+        }
+
+        self.round += 1;
+
+        let mut moved = false;
+        for (to, prop) in propositions {
+            if let Proposition::Single(from) = prop {
+                assert!(self.map.remove(from).is_some());
+                assert!(self.map.insert(to, ()).is_none());
+                moved = true;
+            }
+        }
+
+        moved || self.rounds.is_some()
+    }
+}
+
+impl Display for Grove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        show_map(&self.map).fmt(f)
+    }
+}
+
+impl Steppable for Grove {
+    fn step(&mut self) -> Result<bool> {
+        Ok(self.spread())
+    }
+}
+
+/// Spreads the elves out for `rounds` rounds, returning the empty ground in
+/// the rectangle around them. Without a number of rounds, goes on until no
+/// elf moves and returns the first round where none did.
+pub fn simulate(map: &SparseGrid<()>, rounds: Option<usize>) -> Result<isize> {
+    let mut grove = Grove::new(map, rounds);
+    record::frame(|| &grove);
+
+    while grove.spread() {
+        record::frame(|| &grove);
+    }
+
+    Ok(match rounds {
+        Some(_) => (grove.map.bounds().volume() - grove.map.len()) as isize,
+        None => grove.round as isize,
+    })
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = SparseGrid<()>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(input: &str) -> Result<SparseGrid<()>> {
+        let tile = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+
+        Ok(parse_all(parse_char_grid(tile), input)?
+            .iter()
+            .filter(|(_, &elf)| elf)
+            .map(|(coord, _)| coord)
+            .collect())
+    }
+
+    fn part1(map: &SparseGrid<()>) -> Result<isize> {
+        simulate(map, Some(10))
+    }
+
+    fn part2(map: &SparseGrid<()>) -> Result<isize> {
+        simulate(map, None)
+    }
+
+    fn simulate(map: &SparseGrid<()>, part: u8) -> Option<Simulation> {
+        let rounds = (part == 1).then_some(10);
+        Some(Box::new(Grove::new(map, rounds)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+        step,
+    };
+
+    use super::{generate, Day23};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day23::parse(INPUT).and_then(|i| Day23::part1(&i)), Ok(110))
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day23::parse(INPUT).and_then(|i| Day23::part2(&i)), Ok(20))
+    }
+
+    #[test]
+    fn simulation() {
+        let map = Day23::parse(INPUT).unwrap();
+        let steps = |part| step::advance(&mut *Day23::simulate(&map, part).unwrap(), 100);
+
+        assert_eq!(steps(1), Ok(10));
+        // The 20th round is the first where no elf moves.
+        assert_eq!(steps(2), Ok(19));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day23>(CASES, generate::input);
+    }
+}
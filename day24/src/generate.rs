@@ -0,0 +1,81 @@
+//! Random puzzle inputs.
+
+use std::collections::{HashSet, VecDeque};
+
+use advent::{coord::Coord, direction::Direction, random::Rng};
+
+use crate::Blizzards;
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// Whether `end` can be reached from `start` at time 0. The blizzards come
+/// back to the same places after a while, so the search only needs to try
+/// each position once per period.
+fn can_cross(blizzards: &Blizzards, start: Coord, end: Coord) -> bool {
+    let (width, height) = blizzards.size();
+    let period = width / gcd(width, height) * height;
+
+    let mut seen = HashSet::from([(start, 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((pos, time)) = queue.pop_front() {
+        if pos == end {
+            return true;
+        }
+
+        let time = time + 1;
+        for next in Direction::ALL.iter().map(|d| pos + d.step()).chain([pos]) {
+            if (next == start || blizzards.is_free(next, time))
+                && seen.insert((next, time % period))
+            {
+                queue.push_back((next, time));
+            }
+        }
+    }
+
+    false
+}
+
+fn valley(rng: &mut Rng) -> String {
+    let (width, height) = (rng.range(1..=8), rng.range(1..=6));
+    let density = rng.range(1..=4) as f64 / 10.;
+
+    let mut rows = vec![format!("#.{}", "#".repeat(width))];
+    for _ in 0..height {
+        let row: String = (0..width)
+            .map(|_| match rng.chance(density) {
+                true => *rng.choose(&['^', '>', 'v', '<']),
+                false => '.',
+            })
+            .collect();
+        rows.push(format!("#{}#", row));
+    }
+    rows.push(format!("{}.#", "#".repeat(width)));
+
+    rows.join("\n") + "\n"
+}
+
+/// A valley the expedition can cross both ways. Since it can wait at either
+/// end for as long as it likes, crossing once from time 0 is enough to
+/// cross at any time.
+pub fn input(rng: &mut Rng) -> String {
+    loop {
+        let valley = valley(rng);
+        let blizzards = Blizzards::new(&valley).expect("Generated valleys are valid");
+        let (width, height) = blizzards.size();
+        let (width, height) = (width as isize, height as isize);
+
+        let entrance = Direction::North.step();
+        let exit = Coord::new(width - 1, height);
+        if can_cross(&blizzards, entrance, exit + Direction::North.step())
+            && can_cross(&blizzards, exit, Coord::zero())
+        {
+            return valley;
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use std::{collections::VecDeque, fmt::Display};
+
+use advent::{
+    coord::{ContainsCoord, Coord},
+    direction::Direction,
+    ensure,
+    grid::Grid,
+    info, invalid,
+    parsers::{parse_all, parse_char_grid},
+    record,
+    search::{astar, Graph},
+    solution::Solution,
+    step::{Simulation, Steppable},
+    Error, Result,
+};
+#[allow(unused_imports)]
+use dbg_pls::pretty;
+
+pub mod generate;
+
+/// The blizzards of the valley at time 0, one grid per direction they blow
+/// in. The coordinates leave out the walls.
+#[derive(Clone, Debug, dbg_pls::DebugPls)]
+pub struct Blizzards {
+    blizzards: [Grid<bool>; Direction::ALL.len()],
+
+    width: usize,
+    height: usize,
+}
+
+impl Blizzards {
+    pub fn new(input: &str) -> Result<Blizzards> {
+        let tile = |c| "#.^>v<".contains(c).then_some(c);
+        let map = parse_all(parse_char_grid(tile), input)?;
+        ensure!(
+            map.width() > 2 && map.height() > 2,
+            "The valley is too small"
+        );
+
+        let height = map.height() - 2;
+        let width = map.width() - 2;
+
+        Self::check_top_and_bottom_row(&map)?;
+
+        let mut blizzards = std::array::from_fn(|_| Grid::new(width, height, false));
+
+        for y in 0..height {
+            for (coord, &ch) in map.row(y + 1) {
+                if coord.x == 0 || coord.x as usize == width + 1 {
+                    ensure!(ch == '#', "The valley should be walled at {}", coord);
+                    continue;
+                }
+
+                let dir = match Direction::from_char(ch) {
+                    Some(dir) => dir,
+                    None if ch == '.' => continue,
+                    None => return Err(invalid!("Wall inside the valley at {}", coord)),
+                };
+
+                blizzards[dir as usize][coord + Coord::new(-1, -1)] = true;
+            }
+        }
+
+        Ok(Blizzards {
+            blizzards,
+            width,
+            height,
+        })
+    }
+
+    /// The size of the valley inside the walls.
+    pub fn size(&self) -> (usize, usize) {
+        (self.width, self.height)
+    }
+
+    /// The opening in the top wall, where the expedition starts.
+    pub fn entrance(&self) -> Coord {
+        Direction::North.step()
+    }
+
+    /// The opening in the bottom wall.
+    pub fn exit(&self) -> Coord {
+        Coord::new(self.width as isize - 1, self.height as isize)
+    }
+
+    fn check_top_and_bottom_row(map: &Grid<char>) -> Result<()> {
+        for (coord, &ch) in map.row(0) {
+            let expected = if coord.x == 1 { '.' } else { '#' };
+            ensure!(ch == expected, "Expected '{}' at {}", expected, coord);
+        }
+
+        for (coord, &ch) in map.row(map.height() - 1) {
+            let expected = if coord.x as usize == map.width() - 2 {
+                '.'
+            } else {
+                '#'
+            };
+            ensure!(ch == expected, "Expected '{}' at {}", expected, coord);
+        }
+
+        Ok(())
+    }
+}
+
+impl Blizzards {
+    fn direction_blizzard(&self, coord: Coord, dir: Direction, time: usize) -> bool {
+        let pos = coord - dir.step() * time as isize;
+        let mod_pos = Coord::new(
+            pos.x.rem_euclid(self.width as isize),
+            pos.y.rem_euclid(self.height as isize),
+        );
+
+        self.blizzards[dir as usize][mod_pos]
+    }
+
+    /// Whether `coord` is inside the valley and clear of blizzards at `time`.
+    pub fn is_free(&self, coord: Coord, time: usize) -> bool {
+        self.blizzards[0].contains_coord(coord)
+            && !Direction::ALL
+                .iter()
+                .any(|&dir| self.direction_blizzard(coord, dir, time))
+    }
+}
+
+impl Display for Blizzards {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        ShowValley {
+            blizzards: self,
+            time: 0,
+            expedition: None,
+        }
+        .fmt(f)
+    }
+}
+
+/// The blizzards at some time, with the expedition if it's shown.
+struct ShowValley<'a> {
+    blizzards: &'a Blizzards,
+    time: usize,
+    expedition: Option<Coord>,
+}
+
+impl Display for ShowValley<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (width, height) = (self.blizzards.width, self.blizzards.height);
+        let opening = |coord| {
+            if self.expedition == Some(coord) {
+                'E'
+            } else {
+                '.'
+            }
+        };
+
+        let entrance = opening(self.blizzards.entrance());
+        writeln!(f, "#{}{:#<width$}", entrance, "", width = width)?;
+        for y in 0..height {
+            write!(f, "#")?;
+            for x in 0..width {
+                let coord = Coord::new(x as isize, y as isize);
+                if self.expedition == Some(coord) {
+                    write!(f, "E")?;
+                    continue;
+                }
+
+                let winds: Vec<bool> = Direction::ALL
+                    .iter()
+                    .map(|&dir| self.blizzards.direction_blizzard(coord, dir, self.time))
+                    .collect();
+
+                let count = winds.iter().filter(|&&w| w).count();
+                let ch = match count {
+                    0 => '.',
+                    1 => {
+                        let dir_idx = winds.iter().position(|&w| w).unwrap();
+                        Direction::ALL[dir_idx].arrow()
+                    }
+                    c @ 2..=9 => (b'0' + c as u8) as char,
+                    c => panic!("Cannot have {} blizzards in one spot!", c),
+                };
+
+                write!(f, "{}", ch)?;
+            }
+
+            writeln!(f, "#")?;
+        }
+
+        let exit = opening(self.blizzards.exit());
+        writeln!(f, "{:#<width$}{}#", "", exit, width = width)
+    }
+}
+
+struct Valley<'a> {
+    blizzards: &'a Blizzards,
+    start: Coord,
+    exit: Coord,
+}
+
+impl Graph for Valley<'_> {
+    /// A position and the time it's reached at.
+    type Node = (Coord, usize);
+
+    fn neighbours(&self, &(coord, time): &Self::Node) -> impl Iterator<Item = Self::Node> {
+        Direction::ALL
+            .iter()
+            .map(move |dir| coord + dir.step())
+            .chain([coord])
+            .filter(move |&next| {
+                next == self.start || next == self.exit || self.blizzards.is_free(next, time + 1)
+            })
+            .map(move |next| (next, time + 1))
+    }
+
+    fn heuristic(&self, &(coord, _): &Self::Node) -> usize {
+        (self.exit - coord).manhattan() as usize
+    }
+}
+
+/// The fastest way for the expedition, leaving the opening at `start` at
+/// `start_time`, to reach the one at `exit`. Each position comes with the
+/// time it's reached at.
+pub fn trip(
+    blizzards: &Blizzards,
+    start: Coord,
+    exit: Coord,
+    start_time: usize,
+) -> Result<Vec<(Coord, usize)>> {
+    let valley = Valley {
+        blizzards,
+        start,
+        exit,
+    };
+
+    let search = astar(&valley, [(start, start_time)], |&(coord, _)| coord == exit);
+    search.path().ok_or(Error::NoSolution)
+}
+
+/// The time the expedition, leaving the opening at `start` at
+/// `start_time`, reaches the one at `exit`.
+pub fn run(blizzards: &Blizzards, start: Coord, exit: Coord, start_time: usize) -> Result<usize> {
+    let path = trip(blizzards, start, exit, start_time)?;
+    for &(coord, time) in &path {
+        record::frame(|| ShowValley {
+            blizzards,
+            time,
+            expedition: Some(coord),
+        });
+    }
+
+    let &(_, time) = path.last().expect("A path has at least its start");
+    Ok(time)
+}
+
+/// The expedition going through the valley, one minute at a time. Each trip
+/// is planned once the previous one is over.
+struct Expedition {
+    blizzards: Blizzards,
+    /// The trips still to plan, from one opening to the other.
+    trips: VecDeque<(Coord, Coord)>,
+    /// The current trip, and how far along it the expedition is.
+    path: Vec<(Coord, usize)>,
+    shown: usize,
+}
+
+impl Display for Expedition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (coord, time) = self.path[self.shown];
+        ShowValley {
+            blizzards: &self.blizzards,
+            time,
+            expedition: Some(coord),
+        }
+        .fmt(f)
+    }
+}
+
+impl Steppable for Expedition {
+    fn step(&mut self) -> Result<bool> {
+        if self.shown + 1 < self.path.len() {
+            self.shown += 1;
+            return Ok(true);
+        }
+
+        let Some((start, exit)) = self.trips.pop_front() else {
+            return Ok(false);
+        };
+
+        let (_, time) = self.path[self.shown];
+        self.path = trip(&self.blizzards, start, exit, time)?;
+        self.shown = 1;
+        Ok(true)
+    }
+
+    fn marker(&self) -> Option<char> {
+        Some('E')
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Blizzards;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Blizzards> {
+        Blizzards::new(input)
+    }
+
+    fn part1(blizzards: &Blizzards) -> Result<usize> {
+        run(blizzards, blizzards.entrance(), blizzards.exit(), 0)
+    }
+
+    fn part2(blizzards: &Blizzards) -> Result<usize> {
+        let (entrance, exit) = (blizzards.entrance(), blizzards.exit());
+
+        let there = run(blizzards, entrance, exit, 0)?;
+        info!("There in {}", there);
+        let back = run(blizzards, exit, entrance, there)?;
+        info!("Back in {}", back);
+        run(blizzards, entrance, exit, back)
+    }
+
+    fn simulate(blizzards: &Blizzards, part: u8) -> Option<Simulation> {
+        let (entrance, exit) = (blizzards.entrance(), blizzards.exit());
+        let trips = match part {
+            1 => vec![(entrance, exit)],
+            _ => vec![(entrance, exit), (exit, entrance), (entrance, exit)],
+        };
+
+        Some(Box::new(Expedition {
+            blizzards: blizzards.clone(),
+            trips: trips.into(),
+            path: vec![(entrance, 0)],
+            shown: 0,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        random::{self, CASES},
+        solution::Solution,
+        step,
+    };
+
+    use super::{generate, Day24};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    #[test]
+    fn part1() {
+        assert_eq!(Day24::parse(INPUT).and_then(|i| Day24::part1(&i)), Ok(18));
+    }
+
+    #[test]
+    fn part2() {
+        assert_eq!(Day24::parse(INPUT).and_then(|i| Day24::part2(&i)), Ok(54));
+    }
+
+    #[test]
+    fn simulation() {
+        let blizzards = Day24::parse(INPUT).unwrap();
+        let steps = |part| step::advance(&mut *Day24::simulate(&blizzards, part).unwrap(), 100);
+
+        assert_eq!(steps(1), Ok(18));
+        assert_eq!(steps(2), Ok(54));
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day24>(CASES, generate::input);
+    }
+}
@@ -0,0 +1,20 @@
+//! Random puzzle inputs.
+
+use advent::random::Rng;
+
+use crate::num_to_snafu;
+
+/// Numbers of up to 20 SNAFU digits, so that the sum of a few of them still
+/// fits in an `i64`.
+pub fn number(rng: &mut Rng) -> i64 {
+    let digits = rng.range(1..=20);
+    let max = 5i64.pow(digits) / 2;
+    rng.range(-max..=max)
+}
+
+/// Positive numbers, like those of the fuel requirements.
+pub fn input(rng: &mut Rng) -> String {
+    (0..rng.range(1..=30))
+        .map(|_| num_to_snafu(number(rng).abs().max(1)) + "\n")
+        .collect()
+}
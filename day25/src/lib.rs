@@ -0,0 +1,148 @@
+use advent::{
+    invalid,
+    parsers::{parse_all, parse_lines},
+    solution::Solution,
+    Result,
+};
+use nom::{branch::alt, character::complete::char, combinator::value, multi::fold_many1, IResult};
+
+pub mod generate;
+
+fn snafu_digit(input: &str) -> IResult<&str, i64> {
+    alt((
+        value(2, char('2')),
+        value(1, char('1')),
+        value(0, char('0')),
+        value(-1, char('-')),
+        value(-2, char('=')),
+    ))(input)
+}
+
+/// Parses a SNAFU number, whose digits go from `=` (-2) to `2`.
+pub fn snafu(input: &str) -> IResult<&str, i64> {
+    fold_many1(snafu_digit, || 0, |acc, curr| acc * 5 + curr)(input)
+}
+
+/// Reads a whole SNAFU number.
+pub fn snafu_to_num(input: &str) -> Result<i64> {
+    Ok(parse_all(snafu, input)?)
+}
+
+/// Writes `num` in SNAFU.
+pub fn num_to_snafu(mut num: i64) -> String {
+    if num == 0 {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+
+    while num.abs() > 0 {
+        num += 2;
+
+        digits.push(match num.rem_euclid(5) - 2 {
+            -2 => '=',
+            -1 => '-',
+            0 => '0',
+            1 => '1',
+            2 => '2',
+            d => unreachable!("Invalid digit ((mod 5) - 2): {} ({:?})", d, d),
+        });
+
+        num = num.div_euclid(5);
+    }
+
+    digits.into_iter().rev().collect()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Vec<i64>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Vec<i64>> {
+        Ok(parse_lines(snafu, input)?)
+    }
+
+    fn part1(nums: &Vec<i64>) -> Result<String> {
+        Ok(num_to_snafu(nums.iter().sum()))
+    }
+
+    fn part2(_: &Vec<i64>) -> Result<String> {
+        Err(invalid!("Day 25 only has one part"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent::{
+        parsers::parse_all,
+        random::{self, CASES},
+        solution::Solution,
+    };
+
+    use super::{generate, num_to_snafu, snafu_to_num, Day25};
+
+    const INPUT: &str = include_str!("../small-in.txt");
+
+    macro_rules! snafu_tests {
+        ( $( ($decimal:expr, $snafu:expr) ),* ) => {
+            $(
+                paste::paste!(
+                    #[test]
+                    fn [<snafu_to_num_ $decimal>]() {
+                        assert_eq!(parse_all(super::snafu, $snafu), Ok($decimal));
+                    }
+                );
+
+                paste::paste!(
+                    #[test]
+                    fn [<num_to_snafu_ $decimal>]() {
+                        assert_eq!(super::num_to_snafu($decimal), $snafu);
+                    }
+                );
+            )*
+        }
+    }
+
+    snafu_tests![
+        (1, "1"),
+        (2, "2"),
+        (3, "1="),
+        (4, "1-"),
+        (5, "10"),
+        (6, "11"),
+        (7, "12"),
+        (8, "2="),
+        (9, "2-"),
+        (10, "20"),
+        (15, "1=0"),
+        (20, "1-0"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0")
+    ];
+
+    #[test]
+    fn part1() {
+        assert_eq!(
+            Day25::parse(INPUT)
+                .and_then(|i| Day25::part1(&i))
+                .as_deref(),
+            Ok("2=-1=0")
+        )
+    }
+
+    #[test]
+    fn generated_inputs() {
+        random::check_solves::<Day25>(CASES, generate::input);
+    }
+
+    #[test]
+    fn snafu_numbers_read_back() {
+        random::check_round_trip(CASES, generate::number, |&n| num_to_snafu(n), snafu_to_num);
+    }
+}